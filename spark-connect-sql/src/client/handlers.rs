@@ -0,0 +1,34 @@
+use crate::spark;
+use arrow::record_batch::RecordBatch;
+
+
+#[derive(Default, Debug, Clone)]
+pub struct AnalyzeHandler {
+    pub schema: Option<spark::DataType>,
+    pub spark_version: Option<String>,
+    pub explain: Option<String>,
+    pub tree_string: Option<String>,
+    pub is_streaming: Option<bool>,
+    pub input_files: Option<Vec<String>>,
+    pub ddl_parse: Option<spark::DataType>,
+    pub same_semantics: Option<bool>,
+    pub semantic_hash: Option<i32>,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct ExecuteHandler {
+    pub batches: Vec<RecordBatch>,
+    pub relation: Option<spark::Relation>,
+    pub result_complete: bool,
+    pub total_count: isize,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct InterruptHandler {
+    pub interrupted_ids: Vec<String>
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct ConfigHandler {
+    pub pairs: Vec<spark::KeyValue>,
+}
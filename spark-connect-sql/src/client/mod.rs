@@ -4,13 +4,15 @@ mod middleware;
 
 pub use self::builder::ChannelBuilder;
 pub use self::middleware::HeaderInterceptor;
-use self::handlers::{AnalyzeHandler, ExecuteHandler, InterruptHandler};
+use self::handlers::{AnalyzeHandler, ConfigHandler, ExecuteHandler, InterruptHandler};
 use crate::spark;
 use crate::spark::spark_connect_service_client::SparkConnectServiceClient;
 use crate::spark::execute_plan_response::ResponseType;
+use crate::retry::{retry, RetryPolicy};
 use crate::SparkError;
 
 use arrow::array::RecordBatch;
+use std::collections::HashSet;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tonic::codec::Streaming;
@@ -35,6 +37,9 @@ pub struct SparkClient {
     handler_analyze: AnalyzeHandler,
     handler_execute: ExecuteHandler,
     handler_interrupt: InterruptHandler,
+    handler_config: ConfigHandler,
+    tags: HashSet<String>,
+    retry_policy: RetryPolicy,
 }
 
 impl SparkClient {
@@ -59,10 +64,21 @@ impl SparkClient {
             handler_analyze: AnalyzeHandler::default(),
             handler_execute: ExecuteHandler::default(),
             handler_interrupt: InterruptHandler::default(),
+            handler_config: ConfigHandler::default(),
             use_reattachable_execute: true,
+            tags: HashSet::new(),
+            retry_policy: RetryPolicy::default(),
         }
     }
 
+    /// Overrides the backoff used to retry transient failures on
+    /// [`analyze`](Self::analyze), [`execute_plan`](Self::execute_plan) and
+    /// [`interrupt`](Self::interrupt) RPCs.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
     /// Return session id
     pub fn session_id(&self) -> String {
         self.session_id.to_string()
@@ -92,6 +108,122 @@ impl SparkClient {
         self.handler_execute.batches.to_owned()
     }
 
+    /// Return key/value pairs from the last [`config`](Self::config) response.
+    pub fn config_pairs(&self) -> Vec<spark::KeyValue> {
+        self.handler_config.pairs.to_owned()
+    }
+
+    /// Return the plan explanation from the last `Explain` analyze response,
+    /// rendered according to whichever `ExplainMode` the request asked for.
+    pub fn explain_string(&self) -> Result<String, SparkError> {
+        self.handler_analyze.explain.to_owned().ok_or_else(|| {
+            SparkError::AnalysisException("Explain response is empty".to_string())
+        })
+    }
+
+    /// Return the plan's tree representation from the last `TreeString`
+    /// analyze response.
+    pub fn tree_string(&self) -> Result<String, SparkError> {
+        self.handler_analyze.tree_string.to_owned().ok_or_else(|| {
+            SparkError::AnalysisException("TreeString response is empty".to_string())
+        })
+    }
+
+    /// Return whether the last analyzed plan is a streaming query, from the
+    /// last `IsStreaming` analyze response.
+    pub fn is_streaming(&self) -> Result<bool, SparkError> {
+        self.handler_analyze.is_streaming.ok_or_else(|| {
+            SparkError::AnalysisException("IsStreaming response is empty".to_string())
+        })
+    }
+
+    /// Return the input file paths from the last `InputFiles` analyze response.
+    pub fn input_files(&self) -> Result<Vec<String>, SparkError> {
+        self.handler_analyze.input_files.to_owned().ok_or_else(|| {
+            SparkError::AnalysisException("InputFiles response is empty".to_string())
+        })
+    }
+
+    /// Return the schema parsed from a DDL string, from the last `DdlParse`
+    /// analyze response. Lets a migration confirm a generated schema string
+    /// parses before it's used to build DDL against Spark.
+    pub fn parsed_ddl(&self) -> Result<spark::DataType, SparkError> {
+        self.handler_analyze.ddl_parse.to_owned().ok_or_else(|| {
+            SparkError::AnalysisException("DdlParse response is empty".to_string())
+        })
+    }
+
+    /// Return the plan fingerprint from the last `SemanticHash` analyze
+    /// response. Two plans with the same semantic hash are (almost
+    /// certainly) semantically equivalent, regardless of surface syntax.
+    pub fn semantic_hash(&self) -> Result<i32, SparkError> {
+        self.handler_analyze.semantic_hash.ok_or_else(|| {
+            SparkError::AnalysisException("SemanticHash response is empty".to_string())
+        })
+    }
+
+    /// Asks Spark whether `this` and `other` are semantically equivalent
+    /// plans, e.g. to detect whether two versions of a generated migration
+    /// plan would have the same effect despite differing surface SQL.
+    pub async fn same_semantics(
+        &mut self,
+        this: spark::Plan,
+        other: spark::Plan,
+    ) -> Result<bool, SparkError> {
+        self.analyze(spark::analyze_plan_request::Analyze::SameSemantics(
+            spark::analyze_plan_request::SameSemantics {
+                target_plan: Some(this),
+                other_plan: Some(other),
+            },
+        )).await?;
+
+        self.handler_analyze.same_semantics.ok_or_else(|| {
+            SparkError::AnalysisException("SameSemantics response is empty".to_string())
+        })
+    }
+
+    /// Enforces the Spark Connect tag invariants: a tag must be non-empty and
+    /// must not contain a comma or whitespace (tags are joined with `,` on
+    /// the wire in `ExecutePlanRequest.tags`, so either would corrupt it).
+    fn validate_tag(tag: &str) -> Result<(), SparkError> {
+        if tag.is_empty() {
+            return Err(SparkError::InvalidArgument("Tag must not be empty".to_string()));
+        }
+        if tag.contains(',') {
+            return Err(SparkError::InvalidArgument("Tag must not contain a comma".to_string()));
+        }
+        if tag.chars().any(char::is_whitespace) {
+            return Err(SparkError::InvalidArgument("Tag must not contain whitespace".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Adds `tag` to the set of tags attached to every subsequent
+    /// [`execute_plan`](Self::execute_plan) request, until removed with
+    /// [`Self::remove_tag`] or [`Self::clear_tags`].
+    pub fn add_tag(&mut self, tag: String) -> Result<(), SparkError> {
+        Self::validate_tag(&tag)?;
+        self.tags.insert(tag);
+        Ok(())
+    }
+
+    /// Removes `tag` from the active tag set, if present.
+    pub fn remove_tag(&mut self, tag: &str) {
+        self.tags.remove(tag);
+    }
+
+    /// Clears every active tag.
+    pub fn clear_tags(&mut self) {
+        self.tags.clear();
+    }
+
+    /// Returns the tags currently attached to executed plans.
+    pub fn get_tags(&self) -> Vec<String> {
+        let mut tags: Vec<String> = self.tags.iter().cloned().collect();
+        tags.sort();
+        tags
+    }
+
     fn validate_session(&self, session_id: &str) -> Result<(), SparkError> {
         if self.session_id() != session_id {
             return Err(SparkError::AnalysisException(format!(
@@ -114,15 +246,41 @@ impl SparkClient {
             analyze: Some(analyze),
         };
         
-        let mut client = self.stub.write().await;
-        let resp = client.analyze_plan(req).await?.into_inner();
-        drop(client);
-        
+        let resp = retry(self.retry_policy, || async {
+            let mut client = self.stub.write().await;
+            let resp = client.analyze_plan(req.clone()).await?.into_inner();
+            drop(client);
+            Ok(resp)
+        }).await?;
+
         self.handle_analyze_response(resp)?;
         
         Ok(self)
     }
 
+    /// Execute a [config request](crate::spark::config_request::Operation)
+    /// against Spark's runtime configuration.
+    pub async fn config(
+        &mut self,
+        operation: spark::config_request::Operation,
+    ) -> Result<&mut Self, SparkError> {
+        let req = spark::ConfigRequest {
+            session_id: self.session_id(),
+            user_context: self.user_context.clone(),
+            client_type: self.builder.user_agent.clone(),
+            operation: Some(operation),
+        };
+
+        let mut client = self.stub.write().await;
+        let resp = client.config(req).await?.into_inner();
+        drop(client);
+
+        self.validate_session(&resp.session_id)?;
+        self.handler_config = ConfigHandler { pairs: resp.pairs };
+
+        Ok(self)
+    }
+
     fn handle_analyze_response(
         &mut self,
         resp: spark::AnalyzePlanResponse,
@@ -137,33 +295,33 @@ impl SparkClient {
                 spark::analyze_plan_response::Result::Schema(schema) => {
                     self.handler_analyze.schema = schema.schema
                 }
-                // spark::analyze_plan_response::Result::Explain(explain) => {
-                //     self.handler_analyze.explain = Some(explain.explain_string)
-                // }
-                // spark::analyze_plan_response::Result::TreeString(tree_string) => {
-                //     self.handler_analyze.tree_string = Some(tree_string.tree_string)
-                // }
+                spark::analyze_plan_response::Result::Explain(explain) => {
+                    self.handler_analyze.explain = Some(explain.explain_string)
+                }
+                spark::analyze_plan_response::Result::TreeString(tree_string) => {
+                    self.handler_analyze.tree_string = Some(tree_string.tree_string)
+                }
                 // spark::analyze_plan_response::Result::IsLocal(is_local) => {
                 //     self.handler_analyze.is_local = Some(is_local.is_local)
                 // }
-                // spark::analyze_plan_response::Result::IsStreaming(is_streaming) => {
-                //     self.handler_analyze.is_streaming = Some(is_streaming.is_streaming)
-                // }
-                // spark::analyze_plan_response::Result::InputFiles(input_files) => {
-                //     self.handler_analyze.input_files = Some(input_files.files)
-                // }
+                spark::analyze_plan_response::Result::IsStreaming(is_streaming) => {
+                    self.handler_analyze.is_streaming = Some(is_streaming.is_streaming)
+                }
+                spark::analyze_plan_response::Result::InputFiles(input_files) => {
+                    self.handler_analyze.input_files = Some(input_files.files)
+                }
                 spark::analyze_plan_response::Result::SparkVersion(spark_version) => {
                     self.handler_analyze.spark_version = Some(spark_version.version)
                 }
-                // spark::analyze_plan_response::Result::DdlParse(ddl_parse) => {
-                //     self.handler_analyze.ddl_parse = ddl_parse.parsed
-                // }
-                // spark::analyze_plan_response::Result::SameSemantics(same_semantics) => {
-                //     self.handler_analyze.same_semantics = Some(same_semantics.result)
-                // }
-                // spark::analyze_plan_response::Result::SemanticHash(semantic_hash) => {
-                //     self.handler_analyze.semantic_hash = Some(semantic_hash.result)
-                // }
+                spark::analyze_plan_response::Result::DdlParse(ddl_parse) => {
+                    self.handler_analyze.ddl_parse = ddl_parse.parsed
+                }
+                spark::analyze_plan_response::Result::SameSemantics(same_semantics) => {
+                    self.handler_analyze.same_semantics = Some(same_semantics.result)
+                }
+                spark::analyze_plan_response::Result::SemanticHash(semantic_hash) => {
+                    self.handler_analyze.semantic_hash = Some(semantic_hash.result)
+                }
                 // spark::analyze_plan_response::Result::Persist(_) => {}
                 // spark::analyze_plan_response::Result::Unpersist(_) => {}
                 // spark::analyze_plan_response::Result::GetStorageLevel(level) => {
@@ -197,9 +355,10 @@ impl SparkClient {
                 req.interrupt_type = interrupt_type.into();
             }
             spark::interrupt_request::InterruptType::Tag => {
-                return Err(SparkError::Unimplemented(
-                    "Tag interrupts are not implemented!".to_string()
-                ))
+                let tag = id_or_tag.expect("Operation tag can not be empty");
+                let interrupt = spark::interrupt_request::Interrupt::OperationTag(tag);
+                req.interrupt_type = interrupt_type.into();
+                req.interrupt = Some(interrupt);
             }
             spark::interrupt_request::InterruptType::OperationId => {
                 let op_id = id_or_tag.expect("Operation ID can not be empty");
@@ -214,10 +373,13 @@ impl SparkClient {
             }
         };
 
-        let mut client = self.stub.write().await;
-        let resp = client.interrupt(req).await?.into_inner();
-        drop(client);
-        
+        let resp = retry(self.retry_policy, || async {
+            let mut client = self.stub.write().await;
+            let resp = client.interrupt(req.clone()).await?.into_inner();
+            drop(client);
+            Ok(resp)
+        }).await?;
+
         self.handler_interrupt = InterruptHandler::default();
         self.handler_interrupt.interrupted_ids = resp.interrupted_ids;
         
@@ -233,12 +395,12 @@ impl SparkClient {
         let mut request = self.new_execute_plan_request();
         request.plan = Some(plan);
 
-        let mut client = self.stub.write().await;
-        let mut stream = client
-            .execute_plan(request)
-            .await?
-            .into_inner();
-        drop(client);
+        let mut stream = retry(self.retry_policy, || async {
+            let mut client = self.stub.write().await;
+            let stream = client.execute_plan(request.clone()).await?.into_inner();
+            drop(client);
+            Ok(stream)
+        }).await?;
 
         self.handler_execute = ExecuteHandler::default();
         self.process_stream(&mut stream).await?;
@@ -268,7 +430,7 @@ impl SparkClient {
                     ),
                 ),
             }],
-            tags: vec![],
+            tags: self.get_tags(),
         }
     }
     
@@ -0,0 +1,85 @@
+//! Runtime configuration access for a [`SparkSession`](crate::SparkSession).
+//!
+//! Mirrors PySpark's `spark.conf` - a handle for reading and mutating Spark
+//! runtime configuration (e.g. `spark.sql.sources.partitionOverwriteMode`,
+//! shuffle partition counts) for the lifetime of a session, backed by Spark
+//! Connect's `ConfigRequest` RPC.
+use crate::client::SparkClient;
+use crate::spark;
+use crate::SparkError;
+
+use std::collections::HashMap;
+
+/// Handle returned by [`SparkSession::conf`](crate::SparkSession::conf) for
+/// reading and mutating Spark runtime configuration.
+#[derive(Clone, Debug)]
+pub struct RunTimeConfig {
+    client: SparkClient,
+}
+
+impl RunTimeConfig {
+    pub(crate) fn new(client: SparkClient) -> Self {
+        Self { client }
+    }
+
+    /// Sets `key` to `value` for this session.
+    pub async fn set(&self, key: &str, value: &str) -> Result<(), SparkError> {
+        self.send(spark::config_request::operation::OpType::Set(
+            spark::config_request::Set {
+                pairs: vec![spark::KeyValue { key: key.to_string(), value: Some(value.to_string()) }],
+            },
+        )).await?;
+
+        Ok(())
+    }
+
+    /// Returns the current value for `key`, or `None` if unset.
+    pub async fn get(&self, key: &str) -> Result<Option<String>, SparkError> {
+        let pairs = self.send(spark::config_request::operation::OpType::Get(
+            spark::config_request::Get { keys: vec![key.to_string()] },
+        )).await?;
+
+        Ok(find(&pairs, key))
+    }
+
+    /// Returns every currently set config key/value pair.
+    pub async fn get_all(&self) -> Result<HashMap<String, Option<String>>, SparkError> {
+        let pairs = self.send(spark::config_request::operation::OpType::GetAll(
+            spark::config_request::GetAll { prefix: None },
+        )).await?;
+
+        Ok(pairs.into_iter().map(|kv| (kv.key, kv.value)).collect())
+    }
+
+    /// Resets `key` to its default value.
+    pub async fn unset(&self, key: &str) -> Result<(), SparkError> {
+        self.send(spark::config_request::operation::OpType::Unset(
+            spark::config_request::Unset { keys: vec![key.to_string()] },
+        )).await?;
+
+        Ok(())
+    }
+
+    /// Returns whether `key` can be modified at runtime.
+    pub async fn is_modifiable(&self, key: &str) -> Result<bool, SparkError> {
+        let pairs = self.send(spark::config_request::operation::OpType::IsModifiable(
+            spark::config_request::IsModifiable { keys: vec![key.to_string()] },
+        )).await?;
+
+        Ok(find(&pairs, key).is_some_and(|v| v == "true"))
+    }
+
+    async fn send(
+        &self,
+        op_type: spark::config_request::operation::OpType,
+    ) -> Result<Vec<spark::KeyValue>, SparkError> {
+        let operation = spark::config_request::Operation { op_type: Some(op_type) };
+
+        let mut client = self.client.clone();
+        Ok(client.config(operation).await?.config_pairs())
+    }
+}
+
+fn find(pairs: &[spark::KeyValue], key: &str) -> Option<String> {
+    pairs.iter().find(|kv| kv.key == key).and_then(|kv| kv.value.clone())
+}
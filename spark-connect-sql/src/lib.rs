@@ -3,9 +3,11 @@
 
 mod io;
 pub mod client;
+mod conf;
 mod error;
 mod literal;
 pub mod query;
+mod retry;
 mod session;
 
 /// Spark Connect gRPC protobuf translated using [tonic].
@@ -14,9 +16,11 @@ pub mod spark {
 }
 
 pub use client::SparkClient;
+pub use conf::RunTimeConfig;
 pub use error::SparkError;
+pub use retry::RetryPolicy;
 pub use session::{SparkSessionBuilder, SparkSession};
-pub use literal::ToLiteral;
+pub use literal::{Conversion, ToLiteral};
 
 #[cfg(test)]
 mod test_utils;
\ No newline at end of file
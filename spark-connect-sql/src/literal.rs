@@ -1,9 +1,11 @@
 use crate::spark::expression::literal::{LiteralType, Decimal, CalendarInterval, Array, Map, Struct};
 use crate::spark::expression::Literal;
 use crate::spark::DataType;
+use crate::SparkError;
+use std::str::FromStr;
 
 #[cfg(feature = "chrono")]
-use chrono::{NaiveDate, NaiveDateTime};
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
 
 /// A trait that allows automatic conversion of Rust primitives and complex types into Spark data types.
 /// 
@@ -107,3 +109,67 @@ impl ToLiteral for NaiveDateTime {
         Literal::from_type(LiteralType::Timestamp(self.and_utc().timestamp_micros()))
     }
 }
+
+/// Declares how a raw string (e.g. a CLI argument or config value) should be
+/// coerced into a [`Literal`], since [`ToLiteral`] alone only covers
+/// statically-typed Rust values and can't express "treat this string as a
+/// timestamp".
+#[derive(Debug, Clone, PartialEq)]
+pub enum Conversion {
+    Bytes,
+    Integer,
+    Float,
+    Boolean,
+    Timestamp,
+    TimestampFmt(String),
+    TimestampTzFmt(String),
+}
+
+impl FromStr for Conversion {
+    type Err = SparkError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        if let Some(fmt) = name.strip_prefix("timestamptz|") {
+            return Ok(Conversion::TimestampTzFmt(fmt.to_string()));
+        }
+        if let Some(fmt) = name.strip_prefix("timestamp|") {
+            return Ok(Conversion::TimestampFmt(fmt.to_string()));
+        }
+
+        match name {
+            "asis" | "bytes" | "string" => Ok(Conversion::Bytes),
+            "int" | "integer" => Ok(Conversion::Integer),
+            "float" => Ok(Conversion::Float),
+            "bool" | "boolean" => Ok(Conversion::Boolean),
+            "timestamp" => Ok(Conversion::Timestamp),
+            other => Err(SparkError::InvalidArgument(format!("Unknown conversion: {other}"))),
+        }
+    }
+}
+
+impl Conversion {
+    /// Parses `raw` according to this conversion and returns the resulting
+    /// [`Literal`], or `SparkError::InvalidArgument` if `raw` doesn't parse
+    /// as the target type.
+    pub fn convert(&self, raw: &str) -> Result<Literal, SparkError> {
+        let invalid = |e: impl std::fmt::Display| {
+            SparkError::InvalidArgument(format!("Could not parse '{raw}': {e}"))
+        };
+
+        match self {
+            Conversion::Bytes => Ok(raw.to_string().to_literal()),
+            Conversion::Integer => raw.parse::<i64>().map(ToLiteral::to_literal).map_err(invalid),
+            Conversion::Float => raw.parse::<f64>().map(ToLiteral::to_literal).map_err(invalid),
+            Conversion::Boolean => raw.parse::<bool>().map(ToLiteral::to_literal).map_err(invalid),
+            Conversion::Timestamp => DateTime::parse_from_rfc3339(raw)
+                .map(|dtm| Literal::from_type(LiteralType::Timestamp(dtm.timestamp_micros())))
+                .map_err(invalid),
+            Conversion::TimestampFmt(fmt) => NaiveDateTime::parse_from_str(raw, fmt)
+                .map(|dtm| Literal::from_type(LiteralType::Timestamp(dtm.and_utc().timestamp_micros())))
+                .map_err(invalid),
+            Conversion::TimestampTzFmt(fmt) => DateTime::parse_from_str(raw, fmt)
+                .map(|dtm| Literal::from_type(LiteralType::Timestamp(dtm.timestamp_micros())))
+                .map_err(invalid),
+        }
+    }
+}
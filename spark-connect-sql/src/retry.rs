@@ -0,0 +1,76 @@
+use crate::SparkError;
+use std::future::Future;
+use std::time::Duration;
+
+/// Configures how [`retry`] backs off between attempts at a transient
+/// Spark Connect RPC failure.
+#[derive(Clone, Copy, Debug)]
+pub struct RetryPolicy {
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub max_retries: u32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+            max_retries: 3,
+        }
+    }
+}
+
+/// Whether `error` is a transient condition worth reissuing the RPC for,
+/// as opposed to one that will fail the exact same way every time (a bad
+/// argument, an auth failure, a genuine analysis error in the query itself).
+pub fn is_transient(error: &SparkError) -> bool {
+    match error {
+        SparkError::Unavailable(_)
+        | SparkError::DeadlineExceeded(_)
+        | SparkError::ResourceExhausted(_)
+        | SparkError::Aborted(_) => true,
+        // `InvalidConnectionUrl` also doubles as the catch-all for
+        // `tonic::transport::Error` (see its `From` impl in error.rs), so a
+        // connection that was merely refused or reset surfaces here too.
+        SparkError::InvalidConnectionUrl(msg) => {
+            let msg = msg.to_lowercase();
+            msg.contains("connection refused")
+                || msg.contains("connection reset")
+                || msg.contains("connection aborted")
+        }
+        _ => false,
+    }
+}
+
+/// Retries `f` with exponential backoff (`base_delay * 2^attempt`, capped at
+/// `max_delay`, plus up to half that much jitter) as long as it keeps
+/// failing with a [transient](is_transient) error and `policy.max_retries`
+/// hasn't been exhausted.
+pub async fn retry<T, F, Fut>(policy: RetryPolicy, mut f: F) -> Result<T, SparkError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, SparkError>>,
+{
+    let mut attempt = 0;
+
+    loop {
+        match f().await {
+            Ok(value) => return Ok(value),
+            Err(error) if attempt < policy.max_retries && is_transient(&error) => {
+                let shift = attempt.min(31);
+                let delay = policy
+                    .base_delay
+                    .saturating_mul(1u32 << shift)
+                    .min(policy.max_delay);
+                let jitter = Duration::from_secs_f64(
+                    rand::random::<f64>() * delay.as_secs_f64() / 2.0,
+                );
+
+                tokio::time::sleep(delay + jitter).await;
+                attempt += 1;
+            }
+            Err(error) => return Err(error),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::builder::ChannelBuilder;
 use crate::client::SparkClient;
+use crate::conf::RunTimeConfig;
 use crate::error::SparkError;
 use crate::middleware::HeaderInterceptor;
 use crate::spark;
@@ -122,6 +123,12 @@ impl SparkSession {
         )
     }
 
+    /// Returns a handle for reading and mutating Spark runtime configuration
+    /// (e.g. shuffle partitions, catalog implementation) for this session.
+    pub fn conf(&self) -> RunTimeConfig {
+        RunTimeConfig::new(self.client())
+    }
+
     /// The version of Spark on which this application is running.
     pub async fn version(&self) -> Result<String, SparkError> {
         let version = spark::analyze_plan_request::Analyze::SparkVersion(
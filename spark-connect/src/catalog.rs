@@ -0,0 +1,121 @@
+//! Native Spark Connect catalog operations.
+//!
+//! Spark Connect exposes catalog introspection (`ListTables`, `ListDatabases`,
+//! `TableExists`, `GetTable`, `SetCurrentDatabase`, `CurrentDatabase`) as
+//! `Relation`s carrying a `catalog` oneof, rather than backend-specific SQL
+//! (`SHOW TABLES`, `information_schema.tables`, ...). `ListTables` in
+//! particular returns a uniform schema (`name`, `catalog`, `namespace`,
+//! `description`, `tableType`, `isTemporary`) regardless of whether the
+//! session is backed by Delta, Iceberg, or plain Hive tables, so a single
+//! parser here covers every catalog without per-backend branching.
+use crate::client::SparkClient;
+use crate::spark;
+use crate::SparkError;
+
+use arrow::array::{Array, BooleanArray, StringArray};
+use arrow::record_batch::RecordBatch;
+
+/// Handle returned by [`SparkSession::catalog`](crate::SparkSession::catalog)
+/// for issuing native Spark Connect catalog relations.
+#[derive(Clone, Debug)]
+pub struct Catalog {
+    client: SparkClient,
+}
+
+impl Catalog {
+    pub(crate) fn new(client: SparkClient) -> Self {
+        Self { client }
+    }
+
+    fn plan(cat_type: spark::catalog::CatType) -> spark::Plan {
+        spark::Plan {
+            op_type: Some(spark::plan::OpType::Root(spark::Relation {
+                common: None,
+                rel_type: Some(spark::relation::RelType::Catalog(spark::Catalog {
+                    cat_type: Some(cat_type),
+                })),
+            })),
+        }
+    }
+
+    async fn collect(&self, cat_type: spark::catalog::CatType) -> Result<Vec<RecordBatch>, SparkError> {
+        let mut client = self.client.clone();
+        Ok(client.execute_plan(Self::plan(cat_type)).await?.batches())
+    }
+
+    /// Lists tables, optionally scoped to `db_name` (defaults to the current database).
+    pub async fn list_tables(&self, db_name: Option<&str>) -> Result<Vec<RecordBatch>, SparkError> {
+        self.collect(spark::catalog::CatType::ListTables(spark::ListTables {
+            db_name: db_name.map(str::to_string),
+        })).await
+    }
+
+    /// Lists databases, optionally filtered by a glob `pattern`.
+    pub async fn list_databases(&self, pattern: Option<&str>) -> Result<Vec<RecordBatch>, SparkError> {
+        self.collect(spark::catalog::CatType::ListDatabases(spark::ListDatabases {
+            pattern: pattern.map(str::to_string),
+        })).await
+    }
+
+    /// Returns whether `table_name` exists, optionally scoped to `db_name`.
+    pub async fn table_exists(&self, table_name: &str, db_name: Option<&str>) -> Result<bool, SparkError> {
+        let batches = self.collect(spark::catalog::CatType::TableExists(spark::TableExists {
+            table_name: table_name.to_string(),
+            db_name: db_name.map(str::to_string),
+        })).await?;
+
+        first_bool(&batches)
+    }
+
+    /// Fetches metadata for `table_name`, optionally scoped to `db_name`.
+    pub async fn get_table(&self, table_name: &str, db_name: Option<&str>) -> Result<Vec<RecordBatch>, SparkError> {
+        self.collect(spark::catalog::CatType::GetTable(spark::GetTable {
+            table_name: table_name.to_string(),
+            db_name: db_name.map(str::to_string),
+        })).await
+    }
+
+    /// Sets the current database for this session.
+    pub async fn set_current_database(&self, db_name: &str) -> Result<(), SparkError> {
+        self.collect(spark::catalog::CatType::SetCurrentDatabase(spark::SetCurrentDatabase {
+            db_name: db_name.to_string(),
+        })).await?;
+
+        Ok(())
+    }
+
+    /// Returns the name of the current database.
+    pub async fn current_database(&self) -> Result<String, SparkError> {
+        let batches = self.collect(spark::catalog::CatType::CurrentDatabase(spark::CurrentDatabase {})).await?;
+
+        first_string(&batches)
+    }
+}
+
+/// Extracts the first row's first column as a bool, for single-scalar catalog responses.
+fn first_bool(batches: &[RecordBatch]) -> Result<bool, SparkError> {
+    let column = batches
+        .first()
+        .map(|batch| batch.column(0))
+        .ok_or_else(|| SparkError::AnalysisException("Catalog response had no batches".to_string()))?;
+
+    let array = column.as_any().downcast_ref::<BooleanArray>().ok_or_else(|| {
+        SparkError::AnalysisException("Expected first column to be BooleanArray".to_string())
+    })?;
+
+    Ok(array.value(0))
+}
+
+/// Extracts the first row's first column as a string, for single-scalar catalog responses.
+fn first_string(batches: &[RecordBatch]) -> Result<String, SparkError> {
+    let column = batches
+        .first()
+        .map(|batch| batch.column(0))
+        .ok_or_else(|| SparkError::AnalysisException("Catalog response had no batches".to_string()))?;
+
+    let array = column.as_any().downcast_ref::<StringArray>().ok_or_else(|| {
+        SparkError::AnalysisException("Expected first column to be StringArray".to_string())
+    })?;
+
+    Ok(array.value(0).to_string())
+}
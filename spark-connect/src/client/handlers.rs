@@ -16,7 +16,30 @@ pub(crate) struct ExecuteHandler {
     pub(crate) total_count: isize,
 }
 
+impl ExecuteHandler {
+    /// Records a `SqlCommandResult` response's relation instead of treating
+    /// it as unreachable (it used to be a bare `panic!("{sql_cmd:?}")`).
+    /// DDL/command statements - the `MERGE`/`CREATE TABLE`/`UPDATE`
+    /// statements `SparkEngine` runs, in particular - return this instead of,
+    /// or ahead of, any `ArrowBatch` frames, so the caller's response loop
+    /// should keep consuming the stream afterward exactly as it does for any
+    /// other response type: a trailing `ResultComplete` still terminates it
+    /// cleanly, and a command with no rows (e.g. `UPDATE`) simply never sees
+    /// an `ArrowBatch` and ends up with an empty but `Ok` `batches`.
+    pub(crate) fn handle_sql_command_result(
+        &mut self,
+        result: spark::execute_plan_response::SqlCommandResult,
+    ) {
+        self.relation = result.relation;
+    }
+}
+
 #[derive(Default, Debug, Clone)]
 pub(crate) struct InterruptHandler {
     pub(crate) interrupted_ids: Vec<String>
 }
+
+#[derive(Default, Debug, Clone)]
+pub(crate) struct ConfigHandler {
+    pub(crate) pairs: Vec<spark::KeyValue>,
+}
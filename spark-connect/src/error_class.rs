@@ -0,0 +1,74 @@
+//! Structured classification of Spark's `errorClass` strings.
+//!
+//! Spark Connect failures carry a dotted `errorClass` (e.g.
+//! `TABLE_OR_VIEW_NOT_FOUND`, `UNRESOLVED_COLUMN.WITH_SUGGESTION`) and a
+//! `messageParameters` map, surfaced to callers via
+//! `SparkError::Spark { class, parameters, message }` once the gRPC status's
+//! `ErrorInfo` detail (or a `FetchErrorDetails` follow-up call) has been
+//! decoded - that decoding happens where `tonic::Status` is turned into a
+//! `SparkError` in `SparkClient`. [`ErrorClass::from`] turns the resulting
+//! `class` string into this enum so callers can `match` on common cases
+//! instead of re-parsing the raw string every time.
+//!
+//! Only the error classes swellow's own engine code cares about (or is
+//! likely to hit - not found/already-exists/syntax/type errors) are given
+//! their own variant; everything else falls back to [`ErrorClass::Other`]
+//! so forward compatibility with newer Spark versions isn't lost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorClass {
+    TableOrViewNotFound,
+    TableOrViewAlreadyExists,
+    DatabaseNotFound,
+    DatabaseAlreadyExists,
+    ParseSyntaxError,
+    UnresolvedColumn,
+    DatatypeMismatch,
+    CannotCastDatatype,
+    DivideByZero,
+    /// Any `errorClass` not recognized above, preserved verbatim.
+    Other(String),
+}
+
+impl From<&str> for ErrorClass {
+    fn from(class: &str) -> Self {
+        // Spark error classes are occasionally suffixed with a sub-class,
+        // e.g. `UNRESOLVED_COLUMN.WITH_SUGGESTION` - match on the prefix so
+        // those still classify correctly.
+        let base = class.split('.').next().unwrap_or(class);
+
+        match base {
+            "TABLE_OR_VIEW_NOT_FOUND" => Self::TableOrViewNotFound,
+            "TABLE_OR_VIEW_ALREADY_EXISTS" => Self::TableOrViewAlreadyExists,
+            "SCHEMA_NOT_FOUND" | "DATABASE_NOT_FOUND" => Self::DatabaseNotFound,
+            "SCHEMA_ALREADY_EXISTS" | "DATABASE_ALREADY_EXISTS" => Self::DatabaseAlreadyExists,
+            "PARSE_SYNTAX_ERROR" => Self::ParseSyntaxError,
+            "UNRESOLVED_COLUMN" => Self::UnresolvedColumn,
+            "DATATYPE_MISMATCH" => Self::DatatypeMismatch,
+            "CANNOT_CAST_DATATYPE" | "CAST_INVALID_INPUT" => Self::CannotCastDatatype,
+            "DIVIDE_BY_ZERO" => Self::DivideByZero,
+            _ => Self::Other(class.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ErrorClass;
+
+    #[test]
+    fn classifies_known_classes() {
+        assert_eq!(ErrorClass::from("TABLE_OR_VIEW_NOT_FOUND"), ErrorClass::TableOrViewNotFound);
+        assert_eq!(
+            ErrorClass::from("UNRESOLVED_COLUMN.WITH_SUGGESTION"),
+            ErrorClass::UnresolvedColumn,
+        );
+    }
+
+    #[test]
+    fn falls_back_to_other_for_unknown_classes() {
+        assert_eq!(
+            ErrorClass::from("SOME_FUTURE_SPARK_VERSIONS_ERROR"),
+            ErrorClass::Other("SOME_FUTURE_SPARK_VERSIONS_ERROR".to_string()),
+        );
+    }
+}
@@ -0,0 +1,24 @@
+use crate::SparkError;
+use arrow::record_batch::RecordBatch;
+use arrow_ipc::writer::StreamWriter;
+
+/// Serializes `batches` to the Arrow IPC stream format, sharing the first
+/// batch's schema. Used to wrap in-memory Arrow data in a `LocalRelation`.
+pub(crate) fn serialize(batches: &[RecordBatch]) -> Result<Vec<u8>, SparkError> {
+    let schema = batches
+        .first()
+        .map(|batch| batch.schema())
+        .ok_or_else(|| SparkError::InvalidArgument("Cannot serialize an empty set of batches".to_string()))?;
+
+    let mut buffer = Vec::new();
+    {
+        let mut writer = StreamWriter::try_new(&mut buffer, &schema)
+            .map_err(|e| SparkError::InvalidArgument(e.to_string()))?;
+        for batch in batches {
+            writer.write(batch).map_err(|e| SparkError::InvalidArgument(e.to_string()))?;
+        }
+        writer.finish().map_err(|e| SparkError::InvalidArgument(e.to_string()))?;
+    }
+
+    Ok(buffer)
+}
@@ -116,10 +116,14 @@ This project is not affiliated with, endorsed by, or sponsored by the Apache Sof
 */
 
 mod io;
+pub mod catalog;
 pub mod client;
+pub mod conf;
 mod error;
+pub mod error_class;
 mod literal;
 pub mod query;
+pub mod row;
 mod session;
 
 /// Spark Connect gRPC protobuf translated using [tonic].
@@ -127,8 +131,12 @@ pub mod spark {
     tonic::include_proto!("spark.connect");
 }
 
+pub use catalog::Catalog;
 pub use client::SparkClient;
+pub use conf::RunTimeConfig;
 pub use error::SparkError;
+pub use error_class::ErrorClass;
+pub use row::{FromArrow, FromRow, RowCollect, TimestampMicros};
 pub use session::{SparkSessionBuilder, SparkSession};
 pub use literal::ToLiteral;
 
@@ -24,7 +24,9 @@
 //! plans, and inspecting results — without exposing internal client plumbing.
 use crate::client::ChannelBuilder;
 use crate::client::HeaderInterceptor;
+use crate::catalog::Catalog;
 use crate::client::SparkClient;
+use crate::conf::RunTimeConfig;
 use crate::spark;
 use crate::spark::spark_connect_service_client::SparkConnectServiceClient;
 use crate::spark::expression::Literal;
@@ -32,6 +34,7 @@ use crate::query::SqlQueryBuilder;
 use crate::SparkError;
 
 use arrow::record_batch::RecordBatch;
+use std::collections::HashSet;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tonic::transport::Channel;
@@ -73,6 +76,34 @@ impl SparkSessionBuilder {
         Self { channel_builder }
     }
 
+    /// Loads extra gRPC metadata headers (e.g. `authorization: Bearer <token>`)
+    /// from `path`, merging them over whatever headers were already parsed
+    /// from the connection string. Each non-empty line must be `key: value`.
+    ///
+    /// This lets credentials rotate (a new token written to `path`) without
+    /// touching the connection string or process environment.
+    pub fn headers_from_file(mut self, path: &str) -> Result<Self, SparkError> {
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            SparkError::InvalidArgument(format!("Failed to read headers file '{path}': {e}"))
+        })?;
+
+        let mut headers = self.channel_builder.headers().unwrap_or_default();
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (key, value) = line.split_once(':').ok_or_else(|| {
+                SparkError::InvalidArgument(format!("Invalid header line in '{path}': '{line}'"))
+            })?;
+            headers.insert(key.trim().to_string(), value.trim().to_string());
+        }
+
+        self.channel_builder = self.channel_builder.with_headers(headers);
+        Ok(self)
+    }
+
     /// Establishes a connection and returns a ready-to-use [`SparkSession`].
     ///
     /// This method performs:
@@ -126,6 +157,11 @@ impl SparkSessionBuilder {
 pub struct SparkSession {
     client: SparkClient,
     session_id: String,
+    /// Tags attached to every plan this session submits from now on, until
+    /// removed - see [`Self::add_tag`]/[`Self::interrupt_tag`]. Held here
+    /// rather than on [`SparkClient`] so cloning a session for concurrent use
+    /// (see [`Self::client`]) still shares one tag set.
+    tags: Arc<RwLock<HashSet<String>>>,
 }
 
 impl SparkSession {
@@ -134,7 +170,7 @@ impl SparkSession {
     /// Usually invoked internally by [`SparkSessionBuilder::build`].
     pub fn new(client: SparkClient) -> Self {
         let session_id = client.session_id().to_string();
-        Self { client, session_id }
+        Self { client, session_id, tags: Arc::new(RwLock::new(HashSet::new())) }
     }
 
      /// Returns the unique session identifier for this connection.
@@ -172,13 +208,33 @@ impl SparkSession {
             })),
         };
         let mut client = self.client();
-        let result = client.execute_plan(plan).await?;
+        let result = client.execute_plan(plan, self.current_tags().await).await?;
 
         Ok(spark::Plan {
             op_type: Some(spark::plan::OpType::Root(result.relation()?)),
         })
     }
 
+    /// Materializes in-memory Arrow `batches` as a lazy [`plan`](crate::spark::Plan),
+    /// via Spark Connect's `LocalRelation`. Lets migration code build seed or
+    /// computed rows in Rust and `MERGE`/`INSERT` them into Delta or Iceberg
+    /// tables without first writing external files.
+    ///
+    /// The returned plan is collected the same way as [`Self::sql`]'s, via [`Self::collect`].
+    pub fn create_dataframe(&self, batches: Vec<RecordBatch>) -> Result<spark::Plan, SparkError> {
+        let data = crate::io::serialize(&batches)?;
+
+        Ok(spark::Plan {
+            op_type: Some(spark::plan::OpType::Root(spark::Relation {
+                common: None,
+                rel_type: Some(spark::relation::RelType::LocalRelation(spark::LocalRelation {
+                    data: Some(data),
+                    schema: None,
+                })),
+            })),
+        })
+    }
+
     /// Alternative ["sqlx-like"](https://docs.rs/sqlx/latest/sqlx/) query interface.
     /// Returns a [`SqlQueryBuilder`] to `bind()` parameters and `execute()`.
     pub fn query(
@@ -192,7 +248,13 @@ impl SparkSession {
     pub async fn collect(&self, plan: spark::Plan) -> Result<Vec<RecordBatch>, SparkError> {
         let mut client = self.client();
 
-        Ok(client.execute_plan(plan).await?.batches())
+        Ok(client.execute_plan(plan, self.current_tags().await).await?.batches())
+    }
+
+    /// Snapshot of the tags currently active on this session, in the form
+    /// `sql`/`collect` attach to the outgoing `ExecutePlanRequest`.
+    async fn current_tags(&self) -> Vec<String> {
+        self.tags.read().await.iter().cloned().collect()
     }
 
     /// Interrupt all running operations.
@@ -215,6 +277,56 @@ impl SparkSession {
         )
     }
 
+    /// Interrupt every operation currently tagged with `tag`.
+    pub async fn interrupt_tag(&self, tag: &str) -> Result<Vec<String>, SparkError> {
+        validate_tag(tag)?;
+
+        Ok(
+            self.client().interrupt(
+                spark::interrupt_request::InterruptType::Tag,
+                Some(tag.to_string()),
+            ).await?.interrupted_ids()
+        )
+    }
+
+    /// Adds `tag` to the set of tags attached to every operation launched by
+    /// this session from now on, until removed with [`Self::remove_tag`] or
+    /// [`Self::clear_tags`].
+    pub async fn add_tag(&self, tag: &str) -> Result<(), SparkError> {
+        validate_tag(tag)?;
+        self.tags.write().await.insert(tag.to_string());
+        Ok(())
+    }
+
+    /// Removes `tag` from the active tag set.
+    pub async fn remove_tag(&self, tag: &str) -> Result<(), SparkError> {
+        self.tags.write().await.remove(tag);
+        Ok(())
+    }
+
+    /// Clears every active tag.
+    pub async fn clear_tags(&self) -> Result<(), SparkError> {
+        self.tags.write().await.clear();
+        Ok(())
+    }
+
+    /// Returns the tags currently attached to operations launched by this session.
+    pub async fn get_tags(&self) -> Result<Vec<String>, SparkError> {
+        Ok(self.current_tags().await)
+    }
+
+    /// Returns a [`RunTimeConfig`] handle for reading and mutating Spark
+    /// runtime configuration (e.g. `spark.sql.shuffle.partitions`) for this session.
+    pub fn conf(&self) -> RunTimeConfig {
+        RunTimeConfig::new(self.client())
+    }
+
+    /// Returns a [`Catalog`] handle for native catalog introspection
+    /// (listing tables/databases, checking existence, switching databases).
+    pub fn catalog(&self) -> Catalog {
+        Catalog::new(self.client())
+    }
+
     /// Request the version of the Spark Connect server.
     pub async fn version(&self) -> Result<String, SparkError> {
         let version = spark::analyze_plan_request::Analyze::SparkVersion(
@@ -227,6 +339,19 @@ impl SparkSession {
     }
 }
 
+/// Enforces the Spark Connect tag invariants: a tag must be non-empty and
+/// must not contain a comma (tags are joined with `,` on the wire in the
+/// `ExecutePlanRequest.tags` field, so a comma would silently split in two).
+fn validate_tag(tag: &str) -> Result<(), SparkError> {
+    if tag.is_empty() {
+        return Err(SparkError::InvalidArgument("Tag must not be empty".to_string()));
+    }
+    if tag.contains(',') {
+        return Err(SparkError::InvalidArgument("Tag must not contain a comma".to_string()));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use crate::test_utils::test_utils::setup_session;
@@ -1,9 +1,13 @@
 use crate::{
+    cli,
+    cli::error::{SwellowError, SwellowErrorKind},
     db,
     directory,
-    parser::ResourceCollection,
+    migration,
+    parser::{ParseError, ResourceCollection, Severity, SeverityPolicy, StatementCollection},
     ux
 };
+use serde::Serialize;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -50,8 +54,16 @@ async fn plan(
     migration_dir: &str,
     current_version_id: Option<i64>,
     target_version_id: Option<i64>,
+    versions: Option<Vec<i64>>,
     direction: &MigrationDirection,
-) -> anyhow::Result<Vec<(i64, PathBuf, ResourceCollection)>> {
+    ignore_locks: bool,
+    lock_mode: db::LockMode,
+    lock_no_wait: bool,
+    json: bool,
+    severity_policy: SeverityPolicy,
+    ignore_missing: bool,
+    allow_dirty: bool,
+) -> anyhow::Result<(Vec<(i64, PathBuf, ResourceCollection, bool)>, Vec<PathBuf>, ResourceCollection)> {
     peck(backend).await?;
 
     tracing::info!("Comencing transaction...");
@@ -59,8 +71,14 @@ async fn plan(
 
     // Acquire a lock on the swellow_records table
     // To ensure no other migration process is underway.
-    tracing::info!("Acquiring lock on records table...");
-    backend.acquire_lock().await?;
+    if ignore_locks {
+        tracing::warn!("--ignore-locks set: skipping lock acquisition on records table.");
+    } else {
+        tracing::info!("Serializing against other migration runs...");
+        backend.acquire_xact_lock(lock_no_wait).await?;
+        tracing::info!("Acquiring lock on records table...");
+        backend.acquire_lock(lock_mode).await?;
+    }
 
     // Determine current migration version
     let latest_version_from_records = backend
@@ -80,27 +98,98 @@ async fn plan(
         // If unavailable, get from table records
         .unwrap_or(latest_version_from_records);
     tracing::info!("Current version resolved: {current_version}");
-    
-    // Disable records with versions greater than the user-specified starting version
-    backend.disable_records(current_version).await?;
+
+    // Every version_id known to the migration directory, used both to
+    // validate an explicit --target-version-id below and to spot applied
+    // versions whose directory has since been deleted (see ignore_missing).
+    let on_disk_versions = directory::collect_versions_from_directory(
+        migration_dir,
+        i64::MIN,
+        i64::MAX,
+    ).unwrap_or_default();
+
+    // An explicit --versions list disables just that set (e.g. after a
+    // partial rollback); otherwise fall back to the usual "everything past
+    // the starting version" cutoff.
+    match &versions {
+        Some(explicit) => {
+            // A typo'd id (e.g. `--versions 3,7,99999`) would otherwise just
+            // vanish from the plan further down with zero feedback -
+            // fetch_records_in is the read-side check for "has this id ever
+            // been applied"; anything it doesn't find that also has no
+            // migration directory on disk is almost certainly a mistake
+            // rather than a not-yet-applied version.
+            let recorded = backend.fetch_records_in(explicit).await?;
+            let unknown: Vec<i64> = explicit.iter()
+                .copied()
+                .filter(|id| !recorded.contains(id) && !on_disk_versions.contains_key(id))
+                .collect();
+
+            if !unknown.is_empty() {
+                tracing::warn!(
+                    "--versions included unknown version id(s) {unknown:?}: no migration \
+                     directory and no record of ever being applied - check for a typo."
+                );
+            }
+
+            backend.disable_records_in(explicit).await?
+        },
+        None => backend.disable_records(current_version).await?,
+    }
+
+    // Strict target-version bounds: an explicit --target-version-id that
+    // names a version absent from the directory is a loud, actionable error
+    // instead of directory::load_in_interval's ambiguous "no migrations
+    // found" - except 0, the sentinel for "roll everything back", and the
+    // current version itself, which is an idempotent success.
+    if let Some(target) = target_version_id {
+        if target != 0 && target != current_version && !on_disk_versions.contains_key(&target) {
+            let mut known: Vec<i64> = on_disk_versions.keys().copied().collect();
+            known.sort_by_key(|id| (id - target).abs());
+            known.truncate(3);
+            known.sort();
+
+            return Err(SwellowError {
+                kind: SwellowErrorKind::UnknownTargetVersion { target, closest: known },
+            }.into());
+        }
+
+        if *direction == MigrationDirection::Up && target < current_version {
+            return Err(SwellowError {
+                kind: SwellowErrorKind::TargetVersionBehindCurrent { target, current: current_version },
+            }.into());
+        }
+    }
 
     // Set direction_string, from_version, and to_version depending on direction
-    let (from_version, to_version) = match direction {
+    let (from_version, to_version) = match (&versions, direction) {
+        // An explicit --versions list may span any range - widen the load
+        // interval to cover it (load_in_interval's lower bound is exclusive,
+        // so back it off by one), then filter down to just that set below.
+        (Some(explicit), _) => (
+            explicit.iter().copied().min().unwrap_or(0).saturating_sub(1),
+            explicit.iter().copied().max().unwrap_or(0),
+        ),
         // Migrate from the last version (excluding) up to the user reference
-        MigrationDirection::Up => (
+        (None, MigrationDirection::Up) => (
             current_version,
             target_version_id.unwrap_or(i64::MAX)
         ),
         // Migrate from the last version (excluding) down to the user reference
-        MigrationDirection::Down => (
+        (None, MigrationDirection::Down) => (
             target_version_id.unwrap_or(0),
             current_version
         ),
     };
 
     tracing::info!("Loading migrations from '{migration_dir}'");
-    // Get version names in migration_directory.
-    let mut migrations = directory::load_in_interval(
+    // Get version names in migration_directory. The per-file StatementCollection
+    // isn't needed past this point, so it's dropped here rather than threaded
+    // through every caller - but first, any of its statements that couldn't be
+    // turned into a resource change are collected, so the plan can flag them
+    // instead of silently pretending they don't exist.
+    let mut parse_errors: Vec<ParseError> = Vec::new();
+    let mut migrations: Vec<(i64, PathBuf, ResourceCollection, bool)> = directory::load_in_interval(
         migration_dir,
         from_version,
         to_version,
@@ -110,15 +199,154 @@ async fn plan(
     .map_err(|e| {
         tracing::error!("Error loading migrations: {}", e);
         std::process::exit(1);
-    })?;
+    })?
+    .into_iter()
+    .map(|(version_id, path, statements, resources, no_transaction)| {
+        // Versions dropped by an explicit --versions list below aren't part
+        // of the plan, so neither are their unparsed statements.
+        if versions.as_ref().map_or(true, |explicit| explicit.contains(&version_id)) {
+            let (_, errors) = ResourceCollection::try_from_statement_collection(&statements);
+            parse_errors.extend(errors);
+        }
+        (version_id, path, resources, no_transaction)
+    })
+    .collect();
+
+    // An explicit --versions list takes precedence over the current/target
+    // interval - narrow the loaded range down to exactly that set.
+    if let Some(explicit) = &versions {
+        migrations.retain(|(version_id, _, _, _)| explicit.contains(version_id));
+    }
 
     // Reverse execution direction if migration direction is down.
     if *direction == MigrationDirection::Down {
         migrations.reverse();
     }
 
-    ux::show_migration_changes(&migrations, direction);
-    Ok(migrations)
+    // Drift detection: any version already recorded as APPLIED/TESTED - not
+    // just those in the range being migrated - must still match the checksum
+    // of its on-disk file, or we abort rather than silently re-running (or
+    // ignoring) a migration that was edited after the fact.
+    // Downgraded to a warning (instead of aborting) under --allow-dirty.
+    // up.sql mismatches are collected across the whole loop and reported
+    // together, so a user sees the full extent of the tampering in one run
+    // instead of aborting at the first one found; a down.sql mismatch aborts
+    // as soon as it's found. Applied versions with no directory left on disk
+    // are reported by the missing-migration check below instead.
+    let mut drifted: Vec<(i64, PathBuf, String, String)> = Vec::new();
+
+    for version_id in backend.fetch_applied_versions().await? {
+        let Some(version_path) = on_disk_versions.get(&version_id) else {
+            continue;
+        };
+
+        if let Some(expected) = backend.fetch_checksum(version_id).await? {
+            let up_path = version_path.join(MigrationDirection::Up.filename());
+
+            // A version applied before checksum tracking existed has no
+            // stored digest to compare against - it's unverifiable, not
+            // necessarily drifted, so warn instead of treating it as a
+            // mismatch.
+            if expected.is_empty() {
+                tracing::warn!(
+                    "Version {version_id} has no stored checksum (applied before checksum \
+                     tracking was added) - skipping drift check."
+                );
+            } else {
+                // Hashed over the file's exact bytes (not the reparsed SQL
+                // below), so the comparison can't be fooled by a file that
+                // parses identically but was edited in a way that matters.
+                let found = db::file_checksum(&up_path)?;
+                if expected != found {
+                    drifted.push((version_id, up_path.clone(), expected, found));
+                }
+            }
+        }
+
+        // down.sql is tracked separately (see DbEngine::upsert_down_checksum)
+        // since it's never parsed into per-resource records and can drift
+        // independently of up.sql.
+        if let Some(expected) = backend.fetch_down_checksum(version_id).await? {
+            let down_path = version_path.join(MigrationDirection::Down.filename());
+            if let Ok(sql) = fs::read_to_string(&down_path) {
+                let found = StatementCollection::from_backend(backend).parse_sql(&sql).checksum();
+
+                if expected != found {
+                    let mismatch = db::EngineError {
+                        kind: db::EngineErrorKind::DownChecksumMismatch {
+                            version_id,
+                            expected,
+                            found,
+                        },
+                    };
+
+                    if allow_dirty {
+                        tracing::warn!("{mismatch} (--allow-dirty set, proceeding anyway).");
+                    } else {
+                        return Err(mismatch.into());
+                    }
+                }
+            }
+        }
+    }
+
+    if !drifted.is_empty() {
+        let drift = db::EngineError { kind: db::EngineErrorKind::DriftedMigrations(drifted) };
+
+        if allow_dirty {
+            tracing::warn!("{drift} (--allow-dirty set, proceeding anyway).");
+        } else {
+            return Err(drift.into());
+        }
+    }
+
+    // Mirror sqlx::migrate::Migrator::validate_applied_migrations: an
+    // APPLIED/TESTED version_id with no corresponding directory on disk means
+    // the migration was deleted after being applied, which would otherwise
+    // pass silently since `directory::load_in_interval` only ever sees what's
+    // still there. Downgraded to a warning (instead of aborting) under
+    // --ignore-missing.
+    let missing_versions: Vec<i64> = backend.fetch_applied_versions().await?
+        .into_iter()
+        .filter(|version_id| !on_disk_versions.contains_key(version_id))
+        .collect();
+
+    if !missing_versions.is_empty() {
+        if ignore_missing {
+            tracing::warn!(
+                "Applied migration(s) {missing_versions:?} have no corresponding directory on disk \
+                 (--ignore-missing set, proceeding anyway)."
+            );
+        } else {
+            return Err(SwellowError {
+                kind: SwellowErrorKind::MissingMigrations(missing_versions),
+            }.into());
+        }
+    }
+
+    // Lemmy-style replaceable schema (see directory::load_replaceable_schema):
+    // loaded here too, purely so its changes show up alongside the versioned
+    // plan - migrate() is the one that actually re-applies it.
+    let (replaceable_files, replaceable_resources) = directory::load_replaceable_schema(migration_dir, backend)?;
+
+    let highest_severity = ux::show_migration_changes(
+        &migrations,
+        direction,
+        json,
+        &parse_errors,
+        &replaceable_resources,
+    );
+
+    if severity_policy == SeverityPolicy::Block && highest_severity == Severity::Destructive {
+        return Err(SwellowError {
+            kind: SwellowErrorKind::FailedPrecondition(format!(
+                "{} plan contains destructive action(s) and --on-destructive=block is set",
+                direction.noun(),
+            )),
+        }.into());
+    }
+
+    Ok((migrations, replaceable_files, replaceable_resources))
 }
 
 /// Executes migrations or rollbacks according to the provided plan and flags.
@@ -127,16 +355,32 @@ pub async fn migrate(
     migration_dir: &str,
     current_version_id: Option<i64>,
     target_version_id: Option<i64>,
+    versions: Option<Vec<i64>>,
     direction: MigrationDirection,
     flag_plan: bool,
     flag_dry_run: bool,
+    ignore_locks: bool,
+    lock_mode: db::LockMode,
+    lock_no_wait: bool,
+    json: bool,
+    severity_policy: SeverityPolicy,
+    ignore_missing: bool,
+    allow_dirty: bool,
 ) -> anyhow::Result<()> {
-    let migrations = plan(
+    let (migrations, replaceable_files, _replaceable_resources) = plan(
         backend,
         migration_dir,
         current_version_id,
         target_version_id,
+        versions,
         &direction,
+        ignore_locks,
+        lock_mode,
+        lock_no_wait,
+        json,
+        severity_policy,
+        ignore_missing,
+        allow_dirty,
     ).await?;
 
     if flag_plan {
@@ -144,13 +388,40 @@ pub async fn migrate(
         return Ok(());
     }
 
-    for (version_id, version_path, resources) in migrations {
+    // Reusable schema objects (see directory::load_replaceable_schema) must
+    // be current *before* a Down runs, in case a version being rolled back
+    // depends on a definition it replaces.
+    if direction == MigrationDirection::Down {
+        apply_replaceable_schema(backend, &replaceable_files, flag_dry_run).await?;
+    }
+
+    for (version_id, version_path, resources, no_transaction) in migrations {
         let file_path = version_path.join(direction.filename());
         tracing::info!("{} to version {}...", direction.verb(), version_id);
 
+        // Postgres et al. apply everything and roll the whole transaction
+        // back at the end for a dry run. Engines without real transactions
+        // (see EngineBackend::supports_atomic_rollback) have no such
+        // rollback to lean on - rollback()/commit() are no-ops for them - and
+        // neither does a version flagged NO_TRANSACTION_ANNOTATION, which
+        // always runs on a standalone connection outside the shared
+        // transaction regardless of engine. Both cases skip execution
+        // entirely instead, or a dry run would apply the statement for real.
+        if flag_dry_run && (no_transaction || !backend.supports_atomic_rollback()) {
+            tracing::info!(
+                "Dry run: skipping execution of version {version_id} ({})",
+                file_path.display()
+            );
+            continue;
+        }
+
         if direction == MigrationDirection::Up {
             // Insert a new migration record for every resource
             tracing::info!("Inserting migration records for version {version_id}");
+            // Hashed once over up.sql's raw bytes - the same digest every
+            // resource row stores, and the one `plan` compares against on
+            // later runs to detect drift.
+            let checksum = db::file_checksum(&file_path)?;
             for resource in resources.iter() {
                 // Skip invalid placeholder records (double NULLs)
                 if resource.name_before == "-1" && resource.name_after == "-1" {
@@ -161,20 +432,100 @@ pub async fn migrate(
                     &resource.name_before,
                     &resource.name_after,
                     version_id,
-                    &file_path,
+                    &checksum,
                 ).await?;
             }
+
+            // Record down.sql's checksum too (if reversible), so a later
+            // run can detect it being edited after this version was applied.
+            let down_path = version_path.join(MigrationDirection::Down.filename());
+            if let Ok(down_sql) = fs::read_to_string(&down_path) {
+                let down_checksum = StatementCollection::from_backend(backend).parse_sql(&down_sql).checksum();
+                backend.upsert_down_checksum(version_id, &down_checksum).await?;
+            }
+        }
+
+        // Mark the record(s) as in-flight before running the script, so a
+        // crash mid-execution leaves an unambiguous FAILED/RUNNING state
+        // instead of whatever status the last successful write left behind.
+        backend.update_record(db::RecordStatus::Running, version_id).await?;
+
+        // Execute migration. A version flagged NO_TRANSACTION_ANNOTATION runs
+        // on a standalone connection instead, since its statements (e.g.
+        // Postgres CREATE INDEX CONCURRENTLY) error inside the shared
+        // transaction.
+        let result = if no_transaction {
+            backend.execute_standalone_script(&file_path).await
+        } else {
+            backend.execute_sql_script(&file_path).await
+        };
+
+        if let Err(e) = result {
+            backend.update_record(db::RecordStatus::Failed, version_id).await?;
+
+            // Engines like Spark have no transaction to roll back, so some of
+            // the script's statements may already be applied - say so plainly
+            // instead of returning what would otherwise read as an
+            // all-or-nothing failure.
+            if !backend.supports_atomic_rollback() || no_transaction {
+                return Err(db::EngineError {
+                    kind: db::EngineErrorKind::PartialMigration {
+                        version_id,
+                        reason: e.to_string(),
+                    },
+                }.into());
+            }
+
+            return Err(e);
         }
 
-        // Execute migration
-        backend.execute_sql_script(&file_path).await?;
         // Update records' status
-        backend.update_record(&direction, version_id).await?;
+        let status = match direction {
+            MigrationDirection::Up => db::RecordStatus::Applied,
+            MigrationDirection::Down => db::RecordStatus::RolledBack,
+        };
+        backend.update_record(status, version_id).await?;
+
+        // A no-transaction migration already ran for real outside the shared
+        // transaction, so a crash before the final commit would otherwise
+        // lose track of it having succeeded - commit its record right away
+        // instead of waiting, then reopen the transaction for whatever
+        // follows.
+        if no_transaction {
+            backend.commit().await?;
+            backend.begin().await?;
+
+            // acquire_xact_lock's lock is transaction-scoped, as is
+            // acquire_lock's under LockMode::Table - the commit above just
+            // released them, and the rest of this run still needs to be
+            // serialized against other `swellow migrate` processes, so
+            // re-acquire both on the transaction begin() just reopened.
+            // LockMode::Advisory's session-level lock survives the commit
+            // and is taken a second time on the same session here.
+            if !ignore_locks {
+                backend.acquire_xact_lock(lock_no_wait).await?;
+                backend.acquire_lock(lock_mode).await?;
+            }
+        }
+
+        // Extend this run's lock lease so a long migration doesn't get its
+        // lock stolen out from under it mid-run.
+        backend.heartbeat().await?;
+    }
+
+    // ... and current *after* an Up, since a version just applied may have
+    // introduced a table/column a view or trigger in here now references.
+    if direction == MigrationDirection::Up {
+        apply_replaceable_schema(backend, &replaceable_files, flag_dry_run).await?;
     }
 
     if flag_dry_run {
         backend.rollback().await?;
-        tracing::info!("Dry run completed - transaction successfully rolled back.");
+        if backend.supports_atomic_rollback() {
+            tracing::info!("Dry run completed - transaction successfully rolled back.");
+        } else {
+            tracing::info!("Dry run completed - no statements were executed.");
+        }
     } else {
         backend.commit().await?;
         tracing::info!("Migration completed - transaction successfully committed.");
@@ -183,6 +534,177 @@ pub async fn migrate(
     Ok(())
 }
 
+/// Re-applies `files` (see `directory::load_replaceable_schema`) in file-name
+/// order, inside whatever transaction the caller already opened. A no-op
+/// when `files` is empty, so a migration directory without a
+/// `replaceable_schema` folder pays nothing extra.
+///
+/// Subject to the same dry-run rule as a versioned migration (see
+/// `EngineBackend::supports_atomic_rollback`): engines with no transaction to
+/// roll back would otherwise apply these files for real during a dry run.
+async fn apply_replaceable_schema(
+    backend: &mut db::EngineBackend,
+    files: &[PathBuf],
+    flag_dry_run: bool,
+) -> anyhow::Result<()> {
+    if files.is_empty() {
+        return Ok(());
+    }
+
+    if flag_dry_run && !backend.supports_atomic_rollback() {
+        tracing::info!("Dry run: skipping re-application of replaceable schema.");
+        return Ok(());
+    }
+
+    tracing::info!("Re-applying replaceable schema ({} file(s))...", files.len());
+    for file in files {
+        backend.execute_sql_script(file).await?;
+    }
+
+    Ok(())
+}
+
+/// Scaffolds a new migration directory for `description` under `migration_dir`,
+/// with an `up.sql`/`down.sql` pair (or just `up.sql` if `reversible` is
+/// false) seeded for `backend`'s dialect. `description` is slugified (see
+/// [`migration::slugify`]) before becoming part of the directory name, same
+/// as [`new`] - otherwise a description containing e.g. `/` or `..` would be
+/// interpolated verbatim into the path and could create nested or escaping
+/// directories under `migration_dir`.
+pub fn add(
+    backend: &db::EngineBackend,
+    migration_dir: &str,
+    description: &str,
+    reversible: bool,
+) -> anyhow::Result<PathBuf> {
+    let version_path = crate::migration::scaffold(
+        migration_dir,
+        &migration::slugify(description),
+        backend,
+        reversible,
+    )?;
+
+    Ok(version_path)
+}
+
+/// Scaffolds a new migration directory for `name` under `migration_dir`,
+/// slugifying `name` (see [`migration::slugify`]) into the directory name.
+pub fn new(
+    backend: &db::EngineBackend,
+    migration_dir: &str,
+    name: &str,
+    reversible: bool,
+) -> anyhow::Result<PathBuf> {
+    let version_path = crate::migration::scaffold(
+        migration_dir,
+        &migration::slugify(name),
+        backend,
+        reversible,
+    )?;
+
+    Ok(version_path)
+}
+
+/// Buckets produced by [`verify`]: every applied version falls into exactly one.
+#[derive(Serialize)]
+pub struct VerifyReport {
+    pub matched: Vec<i64>,
+    pub modified: Vec<i64>,
+    pub missing: Vec<i64>,
+}
+
+impl VerifyReport {
+    /// Whether any version's on-disk file has drifted or disappeared.
+    pub fn has_drift(&self) -> bool {
+        !self.modified.is_empty() || !self.missing.is_empty()
+    }
+}
+
+/// Detects drift between applied migrations and their on-disk files.
+///
+/// For every record with status `APPLIED`/`TESTED`, recomputes the checksum
+/// (see [`db::file_checksum`]) of the corresponding version's
+/// `up.sql` and compares it to what's stored in `swellow_records`. Unlike the
+/// drift check embedded in [`plan`], this scans every applied version
+/// regardless of the currently targeted migration range, so it can run
+/// standalone (e.g. in CI) without attempting to migrate anything.
+pub async fn verify(backend: &mut db::EngineBackend, migration_dir: &str) -> anyhow::Result<VerifyReport> {
+    peck(backend).await?;
+    backend.begin().await?;
+
+    let on_disk = migration::collect_versions_from_directory(
+        migration_dir,
+        i64::MIN,
+        i64::MAX,
+    ).unwrap_or_default();
+
+    let mut report = VerifyReport { matched: vec![], modified: vec![], missing: vec![] };
+
+    for version_id in backend.fetch_applied_versions().await? {
+        let expected = match backend.fetch_checksum(version_id).await? {
+            Some(checksum) => checksum,
+            None => continue,
+        };
+
+        let up_path = match on_disk.get(&version_id) {
+            Some(version_path) => version_path.join(MigrationDirection::Up.filename()),
+            None => {
+                report.missing.push(version_id);
+                continue;
+            }
+        };
+
+        // A version applied before checksum tracking existed has no stored
+        // digest to compare against - unverifiable, not necessarily
+        // drifted, so it's counted as matched rather than modified.
+        if expected.is_empty() {
+            tracing::warn!(
+                "Version {version_id} has no stored checksum (applied before checksum \
+                 tracking was added) - skipping drift check."
+            );
+            report.matched.push(version_id);
+            continue;
+        }
+
+        let found = match db::file_checksum(&up_path) {
+            Ok(checksum) => checksum,
+            Err(_) => {
+                report.missing.push(version_id);
+                continue;
+            }
+        };
+
+        if expected != found {
+            report.modified.push(version_id);
+            continue;
+        }
+
+        // down.sql is optional (irreversible migrations have none) and
+        // tracked separately from up.sql's checksum above.
+        if let Some(down_expected) = backend.fetch_down_checksum(version_id).await? {
+            let down_path = on_disk[&version_id].join(MigrationDirection::Down.filename());
+            match fs::read_to_string(&down_path) {
+                Ok(down_sql) => {
+                    let down_found = StatementCollection::from_backend(backend).parse_sql(&down_sql).checksum();
+                    if down_expected != down_found {
+                        report.modified.push(version_id);
+                        continue;
+                    }
+                }
+                Err(_) => {
+                    report.missing.push(version_id);
+                    continue;
+                }
+            }
+        }
+
+        report.matched.push(version_id);
+    }
+
+    backend.rollback().await?;
+    Ok(report)
+}
+
 /// Takes a snapshot of the current database schema and stores it as a new migration.
 pub fn snapshot(backend: &mut db::EngineBackend, migration_dir: &str) -> anyhow::Result<()> {
     tracing::info!("Taking database snapshot...");
@@ -209,3 +731,24 @@ pub fn snapshot(backend: &mut db::EngineBackend, migration_dir: &str) -> anyhow:
     tracing::info!("Snapshot created at version {} 🐦", new_version);
     Ok(())
 }
+
+/// Provisions the target database ahead of the normal connection, since
+/// nothing else can connect to a database that doesn't exist yet. Currently
+/// only implemented for Postgres - every other engine's storage (Spark
+/// catalogs, SQLite/MySQL files) is provisioned outside of swellow's reach.
+pub async fn create(
+    engine: cli::Engine,
+    conn_str: &str,
+    tls: db::TlsConfig,
+    if_not_exists: bool,
+) -> anyhow::Result<()> {
+    tracing::info!("Creating database...");
+
+    match engine {
+        cli::Engine::Postgres => db::create_database(conn_str, tls, if_not_exists).await?,
+        _ => anyhow::bail!("`create` is only supported for the Postgres engine"),
+    }
+
+    tracing::info!("Database created 🐦");
+    Ok(())
+}
@@ -23,22 +23,59 @@ impl Error for SwellowError {
 }
 
 #[derive(Debug)]
-pub enum SwellowErrorKind {    
+pub enum SwellowErrorKind {
     Engine(EngineError),
+    /// A migration plan crossed the configured destructive-action severity
+    /// policy and execution was blocked before anything ran.
+    FailedPrecondition(String),
     InvalidVersionInterval(i64, i64),
     IoDirectoryCreate { source: std::io::Error, path: PathBuf},
+    IoFileRead { source: std::io::Error, path: PathBuf},
     IoFileWrite { source: std::io::Error, path: PathBuf},
+    MissingDbConnectionString,
+    /// A version_id is recorded as `APPLIED`/`TESTED` in `swellow_records` but
+    /// its migration directory is no longer on disk. Downgraded to a warning
+    /// instead of this error when `--ignore-missing` is set.
+    MissingMigrations(Vec<i64>),
     Parse(ParseError),
+    /// `Up` was asked to target a version older than the one currently
+    /// applied - not a no-op, since the caller explicitly named a version.
+    TargetVersionBehindCurrent { target: i64, current: i64 },
+    /// An explicit `--target-version-id` names a version absent from the
+    /// migration directory. `closest` lists the nearest known version IDs so
+    /// the caller can spot a typo instead of guessing.
+    UnknownTargetVersion { target: i64, closest: Vec<i64> },
 }
 
 impl fmt::Display for SwellowErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Engine(error) => write!(f, "{}", error.kind),
+            Self::FailedPrecondition(message) => write!(f, "Failed precondition: {message}"),
             Self::InvalidVersionInterval(from, to) => write!(f, "Invalid version interval: from ({from}) > to ({to})"),
             Self::IoDirectoryCreate { path, .. } => write!(f, "Failed to create directory: '{path:?}'"),
+            Self::IoFileRead { path, .. } => write!(f, "Failed to read file: '{path:?}'"),
             Self::IoFileWrite { path, .. } => write!(f, "Failed to write to file: '{path:?}'"),
-            Self::Parse(error) => write!(f, "{}", error.kind)
+            Self::MissingDbConnectionString => write!(
+                f,
+                "No database connection string provided: set one of --db, --db-file, \
+                 DB_CONNECTION_STRING, or DB_CONNECTION_STRING_FILE",
+            ),
+            Self::MissingMigrations(version_ids) => write!(
+                f,
+                "Applied migration(s) {version_ids:?} have no corresponding directory on disk \
+                 (deleted after being applied). Pass --ignore-missing to downgrade this to a warning.",
+            ),
+            Self::Parse(error) => write!(f, "{}", error.kind),
+            Self::TargetVersionBehindCurrent { target, current } => write!(
+                f,
+                "Target version {target} is older than the current applied version {current}; \
+                 refusing to silently no-op. Use 'down' to roll back instead.",
+            ),
+            Self::UnknownTargetVersion { target, closest } => write!(
+                f,
+                "No migration with version_id {target} exists. Closest known version(s): {closest:?}",
+            ),
         }
         
     }
@@ -49,6 +86,7 @@ impl Error for SwellowErrorKind {
 		match self {
 			Self::Engine(source) => Some(source),
 			Self::IoDirectoryCreate { source, .. } => Some(source),
+			Self::IoFileRead { source, .. } => Some(source),
 			Self::IoFileWrite { source, .. } => Some(source),
             Self::Parse(source) => Some(source),
             _ => None
@@ -4,6 +4,8 @@ pub mod output;
 pub mod ux;
 
 use crate::db;
+use crate::parser;
+use std::path::PathBuf;
 pub use clap::{Parser, Subcommand, ValueEnum};
 
 
@@ -13,14 +15,75 @@ pub enum Engine {
     Postgres,
     SparkDelta,
     SparkIceberg,
+    Sqlite,
+    MySql,
+}
+
+/// User-facing Postgres TLS mode, mirroring libpq's `sslmode` spectrum.
+/// Ignored by every other engine.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, ValueEnum)]
+pub enum SslMode {
+    #[default]
+    Disable,
+    Prefer,
+    Require,
+    VerifyCa,
+    VerifyFull,
+}
+
+impl From<SslMode> for db::SslMode {
+    fn from(mode: SslMode) -> Self {
+        match mode {
+            SslMode::Disable => db::SslMode::Disable,
+            SslMode::Prefer => db::SslMode::Prefer,
+            SslMode::Require => db::SslMode::Require,
+            SslMode::VerifyCa => db::SslMode::VerifyCa,
+            SslMode::VerifyFull => db::SslMode::VerifyFull,
+        }
+    }
 }
 
 impl Engine {
-    pub async fn into_backend(self, conn_str: String) -> Result<db::EngineBackend, db::EngineError> {
+    /// Builds the backend for this engine. `pool_size`, if set, overrides the
+    /// driver's default connection pool size (ignored by the Spark backends,
+    /// which hold a single gRPC session rather than a SQL connection pool).
+    /// `spark_headers_file`, if set, is read for extra gRPC metadata headers
+    /// on the Spark backends (ignored by the SQL backends). `tls` configures
+    /// encryption for the Postgres backend (ignored by every other engine).
+    pub async fn into_backend(
+        self,
+        conn_str: String,
+        pool_size: Option<u32>,
+        spark_headers_file: Option<&str>,
+        tls: db::TlsConfig,
+    ) -> Result<db::EngineBackend, db::EngineError> {
         match self {
-            Engine::Postgres => Ok(db::EngineBackend::Postgres(db::PostgresEngine::new(&conn_str))),
-            Engine::SparkDelta => Ok(db::EngineBackend::SparkDelta(db::SparkEngine::new(&conn_str, db::SparkCatalog::Delta).await?)),
-            Engine::SparkIceberg => Ok(db::EngineBackend::SparkIceberg(db::SparkEngine::new(&conn_str, db::SparkCatalog::Iceberg).await?)),
+            Engine::Postgres => {
+                let mut pool_config = deadpool_postgres::Config::default();
+                if let Some(pool_size) = pool_size {
+                    pool_config.pool = Some(deadpool_postgres::PoolConfig {
+                        max_size: pool_size as usize,
+                        ..Default::default()
+                    });
+                }
+                Ok(db::EngineBackend::Postgres(db::PostgresEngine::with_pool_config(&conn_str, pool_config, tls).await?))
+            },
+            Engine::SparkDelta => Ok(db::EngineBackend::SparkDelta(db::SparkEngine::new(&conn_str, db::SparkCatalog::Delta, spark_headers_file).await?)),
+            Engine::SparkIceberg => Ok(db::EngineBackend::SparkIceberg(db::SparkEngine::new(&conn_str, db::SparkCatalog::Iceberg, spark_headers_file).await?)),
+            Engine::Sqlite => {
+                let mut options = sqlx::sqlite::SqlitePoolOptions::new();
+                if let Some(pool_size) = pool_size {
+                    options = options.max_connections(pool_size);
+                }
+                Ok(db::EngineBackend::Sqlite(db::SqliteEngine::with_pool_options(&conn_str, options).await?))
+            },
+            Engine::MySql => {
+                let mut options = sqlx::mysql::MySqlPoolOptions::new();
+                if let Some(pool_size) = pool_size {
+                    options = options.max_connections(pool_size);
+                }
+                Ok(db::EngineBackend::MySql(db::MySqlEngine::with_pool_options(&conn_str, options).await?))
+            },
         }
     }
 }
@@ -32,11 +95,20 @@ pub struct Cli {
     #[arg(
         long = "db",
         help = "Database connection string. Please follow your database's recommended format, e.g.:
-    postgresql://<username>:<password>@<host>:<port>/<database>\n",
+    postgresql://<username>:<password>@<host>:<port>/<database>\n
+Mutually exclusive with --db-file.",
         env = "DB_CONNECTION_STRING",
-        hide_env_values = true
+        hide_env_values = true,
+        conflicts_with = "db_connection_string_file",
     )]
-    pub db_connection_string: String,
+    pub db_connection_string: Option<String>,
+
+    #[arg(
+        long = "db-file",
+        help = "Path to a file containing the database connection string, read instead of --db so the\nsecret never has to live on the command line or in an environment variable. Trailing\nwhitespace is trimmed. Mutually exclusive with --db.",
+        env = "DB_CONNECTION_STRING_FILE",
+    )]
+    pub db_connection_string_file: Option<PathBuf>,
 
     #[arg(
         long = "dir",
@@ -54,6 +126,51 @@ pub struct Cli {
     )]
     pub engine: Engine,
 
+    #[arg(
+        long = "pool-size",
+        help = "Maximum number of pooled connections to the database. Defaults to the driver's own default.\nIgnored by the Spark backends.",
+        env = "POOL_SIZE",
+    )]
+    pub pool_size: Option<u32>,
+
+    #[arg(
+        long = "spark-headers-file",
+        help = "Path to a file of extra gRPC metadata headers for the Spark Connect engines, one\n'key: value' pair per line (e.g. 'authorization: Bearer <token>'). Lets credentials\nrotate without changing --db. Ignored by the SQL backends.",
+        env = "SPARK_HEADERS_FILE",
+    )]
+    pub spark_headers_file: Option<String>,
+
+    #[arg(
+        long = "ssl-mode",
+        value_enum,
+        default_value_t = SslMode::Disable,
+        help = "Postgres TLS mode, mirroring libpq's sslmode spectrum. Ignored by non-Postgres engines.",
+        env = "SSL_MODE",
+    )]
+    pub ssl_mode: SslMode,
+
+    #[arg(
+        long = "ssl-ca-file",
+        help = "Path to a PEM-encoded CA certificate used to verify the Postgres server.\nRequired for --ssl-mode verify-ca/verify-full.",
+        env = "SSL_CA_FILE",
+    )]
+    pub ssl_ca_file: Option<PathBuf>,
+
+    #[arg(
+        long = "ssl-client-identity-file",
+        help = "Path to a PKCS#12 bundle (certificate + private key) for mutual TLS client authentication.",
+        env = "SSL_CLIENT_IDENTITY_FILE",
+    )]
+    pub ssl_client_identity_file: Option<PathBuf>,
+
+    #[arg(
+        long = "ssl-client-identity-passphrase",
+        help = "Passphrase for --ssl-client-identity-file.",
+        env = "SSL_CLIENT_IDENTITY_PASSPHRASE",
+        hide_env_values = true,
+    )]
+    pub ssl_client_identity_passphrase: Option<String>,
+
     #[arg(
         short,
         long,
@@ -77,10 +194,190 @@ pub struct Cli {
     )]
     pub json: bool,
 
+    #[arg(
+        long = "connect-timeout",
+        help = "Seconds to keep retrying a transient connection failure (e.g. a database still\nbooting) before giving up. Ignored if the failure isn't transient - see --max-retries.",
+        default_value_t = 30,
+        env = "CONNECT_TIMEOUT",
+    )]
+    pub connect_timeout_secs: u64,
+
+    #[arg(
+        long = "max-retries",
+        help = "Maximum number of retries for a transient connection failure, on top of --connect-timeout.",
+        default_value_t = 5,
+        env = "MAX_RETRIES",
+    )]
+    pub max_retries: u32,
+
+    #[arg(
+        long = "auto-create-database",
+        action = clap::ArgAction::SetTrue,
+        help = "Create the target database first if it doesn't already exist, the same way `swellow create\n--if-not-exists` would, before connecting. Postgres only. Off by default so production runs\ndon't accidentally create a database from a typo'd connection string.",
+        env = "AUTO_CREATE_DATABASE",
+    )]
+    pub auto_create_database: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
 
+impl Cli {
+    /// Resolves the configured database connection string, preferring
+    /// [`Self::db_connection_string_file`] (trimmed of trailing whitespace)
+    /// when set, so secrets don't have to live on the command line or in an
+    /// environment variable. `--db` and `--db-file` are mutually exclusive
+    /// (enforced by clap); this errors if neither was set.
+    pub fn resolve_db_connection_string(&self) -> Result<String, error::SwellowError> {
+        if let Some(path) = &self.db_connection_string_file {
+            let contents = std::fs::read_to_string(path).map_err(|source| error::SwellowError {
+                kind: error::SwellowErrorKind::IoFileRead { source, path: path.clone() },
+            })?;
+            return Ok(contents.trim_end().to_string());
+        }
+
+        self.db_connection_string.clone().ok_or(error::SwellowError {
+            kind: error::SwellowErrorKind::MissingDbConnectionString,
+        })
+    }
+
+    /// Resolves the configured Postgres TLS settings, reading
+    /// [`Self::ssl_ca_file`]/[`Self::ssl_client_identity_file`] from disk if
+    /// set. Ignored by every non-Postgres engine.
+    pub fn resolve_tls_config(&self) -> Result<db::TlsConfig, error::SwellowError> {
+        let ca_certificate_pem = self.ssl_ca_file.as_ref()
+            .map(|path| std::fs::read(path).map_err(|source| error::SwellowError {
+                kind: error::SwellowErrorKind::IoFileRead { source, path: path.clone() },
+            }))
+            .transpose()?;
+
+        let client_identity_pkcs12 = self.ssl_client_identity_file.as_ref()
+            .map(|path| -> Result<_, error::SwellowError> {
+                let pkcs12 = std::fs::read(path).map_err(|source| error::SwellowError {
+                    kind: error::SwellowErrorKind::IoFileRead { source, path: path.clone() },
+                })?;
+                Ok((pkcs12, self.ssl_client_identity_passphrase.clone().unwrap_or_default()))
+            })
+            .transpose()?;
+
+        Ok(db::TlsConfig {
+            mode: self.ssl_mode.into(),
+            ca_certificate_pem,
+            client_identity_pkcs12,
+        })
+    }
+
+    /// Establishes the backend connection, retrying with exponential backoff
+    /// while the failure looks transient (a database or connection pooler
+    /// still booting - see [`is_transient_connect_error`]), up to
+    /// [`Self::max_retries`] retries or [`Self::connect_timeout_secs`]
+    /// elapsed, whichever comes first. A permanent failure (bad connection
+    /// string, bad credentials) is returned immediately without retrying.
+    pub async fn connect_with_retry(&self, conn_str: String, tls: db::TlsConfig) -> Result<db::EngineBackend, db::EngineError> {
+        const INITIAL_BACKOFF: std::time::Duration = std::time::Duration::from_millis(200);
+        const BACKOFF_MULTIPLIER: f64 = 2.0;
+
+        let started_at = std::time::Instant::now();
+        let deadline = started_at + std::time::Duration::from_secs(self.connect_timeout_secs);
+        let mut backoff = INITIAL_BACKOFF;
+        let mut attempt = 0u32;
+
+        loop {
+            let result = self.engine.into_backend(
+                conn_str.clone(),
+                self.pool_size,
+                self.spark_headers_file.as_deref(),
+                tls.clone(),
+            ).await;
+
+            let error = match result {
+                Ok(backend) => return Ok(backend),
+                Err(e) => e,
+            };
+
+            let now = std::time::Instant::now();
+            if attempt >= self.max_retries || now >= deadline || !is_transient_connect_error(&error) {
+                return Err(error);
+            }
+
+            attempt += 1;
+            tracing::warn!(
+                "Transient error connecting to the backend (attempt {attempt}/{}, {:.1}s elapsed): {error}. Retrying in {:.1}s...",
+                self.max_retries,
+                now.duration_since(started_at).as_secs_f64(),
+                backoff.as_secs_f64(),
+            );
+
+            tokio::time::sleep(backoff.min(deadline.saturating_duration_since(now))).await;
+            backoff = backoff.mul_f64(BACKOFF_MULTIPLIER);
+        }
+    }
+}
+
+/// Whether a backend-connection `error` looks transient - the server not
+/// being up yet - as opposed to permanent (bad connection string, bad
+/// credentials, unsupported auth). Only connection *establishment* failures
+/// are considered; anything else (e.g. a query failing after a connection
+/// was already made) is always permanent here.
+fn is_transient_connect_error(error: &db::EngineError) -> bool {
+    use std::io::ErrorKind;
+
+    match &error.kind {
+        db::EngineErrorKind::SQLX(sqlx::Error::Io(io_err)) => matches!(
+            io_err.kind(),
+            ErrorKind::ConnectionRefused | ErrorKind::ConnectionReset | ErrorKind::ConnectionAborted,
+        ),
+        // Spark Connect's gRPC channel reports "server not listening yet" as
+        // Unavailable - the closest equivalent to a SQL driver's connect-time
+        // connection-refused error.
+        db::EngineErrorKind::Spark(spark_connect::SparkError::Unavailable(_)) => true,
+        _ => false,
+    }
+}
+
+/// Postgres locking strategy for serializing concurrent `swellow` runs.
+/// Ignored by every other engine, which has its own native locking scheme.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, ValueEnum)]
+pub enum LockMode {
+    /// `LOCK TABLE swellow_records IN ACCESS EXCLUSIVE MODE`, blocking all
+    /// readers of the records table for the whole migration.
+    #[default]
+    Table,
+    /// Session-level `pg_try_advisory_lock`, which still serializes concurrent
+    /// `swellow` processes but lets ordinary queries read the records table
+    /// during a long migration.
+    Advisory,
+}
+
+impl From<LockMode> for db::LockMode {
+    fn from(mode: LockMode) -> Self {
+        match mode {
+            LockMode::Table => db::LockMode::Table,
+            LockMode::Advisory => db::LockMode::Advisory,
+        }
+    }
+}
+
+/// What to do when a migration plan contains a destructive (e.g. `DROP`,
+/// `TRUNCATE`) or warning-level (e.g. `ALTER`, `RENAME`) change.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, ValueEnum)]
+pub enum SeverityPolicy {
+    /// Log a warning but proceed anyway.
+    #[default]
+    Warn,
+    /// Abort before executing anything if the plan is destructive.
+    Block,
+}
+
+impl From<SeverityPolicy> for parser::SeverityPolicy {
+    fn from(policy: SeverityPolicy) -> Self {
+        match policy {
+            SeverityPolicy::Warn => parser::SeverityPolicy::Warn,
+            SeverityPolicy::Block => parser::SeverityPolicy::Block,
+        }
+    }
+}
+
 #[derive(Parser)]
 pub struct SwellowArgs {
     #[arg(
@@ -115,6 +412,52 @@ If no record is enabled, swellow will assume the current version to be 0.",
         help = "Ignore acquiring locks. ⚠️ Warning: sequential execution of migrations is not guaranteed when this flag is set.",
     )]
     pub ignore_locks: bool,
+
+    #[arg(
+        long,
+        help = "Downgrade to a warning, instead of aborting, when an applied migration's version_id\nhas no corresponding directory on disk (e.g. it was deleted after being applied).",
+    )]
+    pub ignore_missing: bool,
+
+    #[arg(
+        long = "allow-dirty",
+        help = "Downgrade to a warning, instead of aborting, when an applied migration's up.sql\nor down.sql no longer matches its stored checksum (e.g. it was edited after being applied).",
+    )]
+    pub allow_dirty: bool,
+
+    #[arg(
+        long = "lock-mode",
+        value_enum,
+        default_value_t = LockMode::Table,
+        help = "Postgres locking strategy: 'table' (ACCESS EXCLUSIVE, default) or 'advisory' (pg_try_advisory_lock). Ignored by non-Postgres engines.",
+    )]
+    pub lock_mode: LockMode,
+
+    #[arg(
+        long = "lock-no-wait",
+        action = clap::ArgAction::SetTrue,
+        help = "Fail immediately, instead of waiting, if another migration run already holds the\n\
+                transaction-scoped advisory lock that serializes concurrent `up`/`down` runs.\n\
+                Postgres only; ignored by non-Postgres engines.",
+    )]
+    pub lock_no_wait: bool,
+
+    #[arg(
+        long = "on-destructive",
+        value_enum,
+        default_value_t = SeverityPolicy::Warn,
+        help = "Policy for destructive migration changes (DROP, TRUNCATE, ...): \
+                'warn' (default, log and proceed) or 'block' (abort before executing).",
+    )]
+    pub on_destructive: SeverityPolicy,
+
+    #[arg(
+        long = "versions",
+        value_delimiter = ',',
+        help = "Explicit, possibly non-contiguous list of version IDs to migrate/disable\n\
+                (e.g. `--versions 3,7,9`), taking precedence over --current-version-id/--target-version-id.",
+    )]
+    pub versions: Option<Vec<i64>>,
 }
 
 #[derive(Subcommand)]
@@ -122,6 +465,19 @@ pub enum Commands {
     #[command(about = "Test connection to the database.")]
     Peck {},
 
+    #[command(about = "Scaffold a new migration directory with empty up.sql/down.sql stubs.")]
+    Add {
+        #[arg(help = "Short description, used as part of the new version's directory name.")]
+        description: String,
+
+        #[arg(
+            long = "no-reversible",
+            action = clap::ArgAction::SetTrue,
+            help = "Skip scaffolding down.sql - the migration is considered irreversible.",
+        )]
+        no_reversible: bool,
+    },
+
     #[command(about = "Generate a migration plan and execute it.")]
     Up {
         #[command(flatten)]
@@ -133,19 +489,51 @@ pub enum Commands {
         args: SwellowArgs,
     },
 
+    #[command(about = "Scaffold a new, empty migration directory (slugifying the given name).")]
+    New {
+        #[arg(help = "Human-readable migration name, slugified into the new version's directory name.")]
+        name: String,
+
+        #[arg(
+            long = "no-reversible",
+            action = clap::ArgAction::SetTrue,
+            help = "Skip scaffolding down.sql - the migration is considered irreversible.",
+        )]
+        no_reversible: bool,
+    },
+
+    #[command(about = "Detect drift between applied migrations and their on-disk files, using stored checksums.")]
+    Verify {},
+
     #[command(about = "Take a snapshot of the database schema into a set of CREATE statements.
 Automatically creates a new version migration subdirectory like '<VERSION>_snapshot'.
-⚠️ Postgres: pg_dump must be installed with a version matching the server's.")]
-    Snapshot {}
+⚠️ Requires the engine's schema-dump CLI on PATH: pg_dump (Postgres, version matching the
+server's), sqlite3 (SQLite), or mysqldump (MySQL).")]
+    Snapshot {},
+
+    #[command(about = "Provision the target database, connecting to a maintenance database first.
+Currently only supported for the Postgres engine.")]
+    Create {
+        #[arg(
+            long = "if-not-exists",
+            action = clap::ArgAction::SetTrue,
+            help = "Do not error if the database already exists.",
+        )]
+        if_not_exists: bool,
+    },
 }
 
 impl std::fmt::Display for Commands {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let name = match self {
             Commands::Peck { .. } => "peck",
+            Commands::Add { .. } => "add",
+            Commands::New { .. } => "new",
+            Commands::Verify { .. } => "verify",
             Commands::Up { .. } => "up",
             Commands::Down { .. } => "down",
             Commands::Snapshot { .. } => "snapshot",
+            Commands::Create { .. } => "create",
         };
         write!(f, "{name}")
     }
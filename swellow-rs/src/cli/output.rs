@@ -14,7 +14,7 @@
 //   },
 //   "timestamp": "2025-10-17T15:52:12Z"
 // }
-use crate::{db::EngineError, error::{SwellowError, SwellowErrorKind}, parser::ParseErrorKind};
+use crate::{db::{EngineError, EngineErrorKind}, error::{SwellowError, SwellowErrorKind}, parser::ParseErrorKind};
 use serde::Serialize;
 use serde_json::Value;
 
@@ -22,24 +22,65 @@ use serde_json::Value;
 #[derive(Debug, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum SwellowErrorJson {
+    Config { message: String },
     Engine { message: String },
     FileNotFound { message: String },
+    /// Postgres SQLSTATE class `23` - a constraint (unique/foreign
+    /// key/check/not-null) was violated.
+    IntegrityConstraintViolation { message: String, sqlstate: String },
     Io { message: String },
+    /// Postgres SQLSTATE class `53` - the server ran out of some resource
+    /// (disk, memory, connections) unrelated to the migration's own SQL.
+    InsufficientResources { message: String, sqlstate: String },
+    /// Postgres SQLSTATE class `57` - the server shut down or cancelled the
+    /// statement (e.g. `pg_cancel_backend`, admin shutdown).
+    OperatorIntervention { message: String, sqlstate: String },
     Parser { message: String },
+    /// Postgres SQLSTATE class `42` - a syntax error or a missing/forbidden
+    /// object/permission.
+    SyntaxOrAccessRule { message: String, sqlstate: String },
+    /// Postgres SQLSTATE class `40` - the transaction was rolled back by the
+    /// server itself (deadlock, serialization failure) rather than by the
+    /// statement failing on its own merits. Usually safe to retry.
+    TransactionRollback { message: String, sqlstate: String },
     Version { message: String },
 }
 
+impl SwellowErrorJson {
+    /// Classifies a Postgres SQLSTATE `code` by its two-character class
+    /// prefix, falling back to [`Self::Engine`] for classes swellow doesn't
+    /// give their own variant (and for non-Postgres database errors, which
+    /// have no SQLSTATE at all).
+    fn from_sqlstate(code: &str, message: String) -> Self {
+        let sqlstate = code.to_string();
+
+        match code.get(..2) {
+            Some("23") => Self::IntegrityConstraintViolation { message, sqlstate },
+            Some("42") => Self::SyntaxOrAccessRule { message, sqlstate },
+            Some("40") => Self::TransactionRollback { message, sqlstate },
+            Some("53") => Self::InsufficientResources { message, sqlstate },
+            Some("57") => Self::OperatorIntervention { message, sqlstate },
+            _ => Self::Engine { message },
+        }
+    }
+}
+
 impl From<&SwellowError> for SwellowErrorJson {
     fn from(error: &SwellowError) -> Self {
         let stderr = format!("{error}");
 
         match &error.kind {
             SwellowErrorKind::DryRunUnsupportedEngine(_) => Self::Engine { message: stderr },
-            SwellowErrorKind::Engine(_) => Self::Engine { message: stderr },
+            SwellowErrorKind::Engine(e) => e.into(),
+            SwellowErrorKind::FailedPrecondition(_) => Self::Config { message: stderr },
             SwellowErrorKind::InvalidVersionInterval(..) => Self::Version { message: stderr },
-            SwellowErrorKind::IoDirectoryCreate {..} | SwellowErrorKind::IoFileWrite {..} => {
+            SwellowErrorKind::IoDirectoryCreate {..} | SwellowErrorKind::IoFileRead {..} | SwellowErrorKind::IoFileWrite {..} => {
                 Self::Io { message: stderr }
             }
+            SwellowErrorKind::MissingDbConnectionString => Self::Config { message: stderr },
+            SwellowErrorKind::MissingMigrations(_) => Self::FileNotFound { message: stderr },
+            SwellowErrorKind::TargetVersionBehindCurrent { .. } => Self::Version { message: stderr },
+            SwellowErrorKind::UnknownTargetVersion { .. } => Self::Version { message: stderr },
             SwellowErrorKind::Parse(e) => match &e.kind {
                 ParseErrorKind::FileNotFound {..} => Self::FileNotFound { message: stderr },
                 ParseErrorKind::InvalidDirectory(_) => Self::Io { message: stderr },
@@ -55,7 +96,15 @@ impl From<&SwellowError> for SwellowErrorJson {
 
 impl From<&EngineError> for SwellowErrorJson {
     fn from(e: &EngineError) -> Self {
-        Self::Engine { message: format!("{e}") }
+        let message = format!("{e}");
+
+        if let EngineErrorKind::SQLX(sqlx::Error::Database(db_err)) = &e.kind {
+            if let Some(code) = db_err.code() {
+                return Self::from_sqlstate(&code, message);
+            }
+        }
+
+        Self::Engine { message }
     }
 }
 
@@ -122,4 +171,26 @@ mod tests {
         assert_eq!(v["command"], "plan");
         assert_eq!(v["error"]["type"], "version");
     }
+
+    #[test]
+    fn classifies_sqlstate_by_class_prefix() {
+        let cases: Vec<(&str, &str)> = vec![
+            ("23505", "integrity_constraint_violation"),
+            ("42601", "syntax_or_access_rule"),
+            ("40001", "transaction_rollback"),
+            ("53100", "insufficient_resources"),
+            ("57014", "operator_intervention"),
+            ("99999", "engine"),
+        ];
+
+        for (code, expected_type) in cases {
+            let json = SwellowErrorJson::from_sqlstate(code, "boom".to_string());
+            let v: Value = serde_json::to_value(&json).unwrap();
+
+            assert_eq!(v["type"], expected_type, "sqlstate {code}");
+            if expected_type != "engine" {
+                assert_eq!(v["sqlstate"], code);
+            }
+        }
+    }
 }
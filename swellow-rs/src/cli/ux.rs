@@ -1,17 +1,11 @@
-use crate::migration::{MigrationCollection, MigrationDirection};
-use crate::parser::Resource;
+use crate::cli::commands::MigrationDirection;
+use crate::parser::{ParseError, ParseErrorKind, ResourceCollection, Severity};
+use serde::Serialize;
 use std::fmt::Write;
+use std::path::PathBuf;
 
 
 pub fn setup_logging(verbose: u8, quiet: bool, json: bool) {
-    if json {
-        // Mute all logging if JSON output is enabled
-        // TODO: Log to a file instead or always
-        tracing::subscriber::set_global_default(tracing::subscriber::NoSubscriber::default())
-            .expect("Setting no-op subscriber failed");
-        return;
-    }
-
     let level = if quiet {
         tracing::Level::ERROR
     } else { match verbose {
@@ -20,77 +14,208 @@ pub fn setup_logging(verbose: u8, quiet: bool, json: bool) {
         _ => tracing::Level::TRACE,
     }};
 
-    let subscriber = tracing_subscriber::FmtSubscriber::builder()
-        .with_max_level(level)
-        .finish();
+    let builder = tracing_subscriber::FmtSubscriber::builder().with_max_level(level);
+
+    if json {
+        // stdout is reserved for the machine-readable output (see
+        // `show_migration_changes` and `main`'s final JSON print), so route
+        // human-readable logs to stderr instead of muting them outright.
+        tracing::subscriber::set_global_default(builder.with_writer(std::io::stderr).finish())
+            .expect("Setting default subscriber failed!");
+    } else {
+        tracing::subscriber::set_global_default(builder.finish())
+            .expect("Setting default subscriber failed!");
+    }
+}
+
+
+/// A machine-readable migration plan, serialized to stdout when `--json` is
+/// set so CI pipelines can gate on `destructive` without scraping log text.
+#[derive(Serialize)]
+pub struct MigrationPlan {
+    pub direction: String,
+    pub migrations: Vec<MigrationPlanEntry>,
+    /// Statements that couldn't be parsed into a resource change (anything
+    /// outside the CREATE/ALTER/DROP lexicon) and are therefore missing from
+    /// `migrations` above, rendered as their raw statement text.
+    pub unparsed_statements: Vec<String>,
+    /// Changes from `replaceable_schema/` (see
+    /// [`crate::migrations::directory::load_replaceable_schema`]) - reusable
+    /// views/functions/triggers reconstructed wholesale every run rather than
+    /// tied to a single version, so they're reported separately from
+    /// `migrations` above instead of against a `version_id`.
+    pub replaceable_schema: Vec<ResourcePlanEntry>,
+}
 
-    tracing::subscriber::set_global_default(subscriber)
-        .expect("Setting default subscriber failed!");
+#[derive(Serialize)]
+pub struct MigrationPlanEntry {
+    pub version_id: i64,
+    pub path: String,
+    pub resources: Vec<ResourcePlanEntry>,
+    pub destructive: bool,
+    /// Set when the migration's first non-blank line is
+    /// [`crate::migrations::directory::NO_TRANSACTION_ANNOTATION`] - it runs
+    /// on a standalone connection outside the shared transaction instead of
+    /// being rolled back together with everything else on failure.
+    pub no_transaction: bool,
 }
 
+#[derive(Serialize)]
+pub struct ResourcePlanEntry {
+    pub object_type: String,
+    pub name_before: String,
+    pub name_after: String,
+    pub statements: Vec<String>,
+    pub severity: Severity,
+}
 
+/// Builds the [`MigrationPlan`] and renders it (JSON on stdout when `json`,
+/// otherwise the human-readable text via `tracing`), returning the highest
+/// [`Severity`] found across every resource so the caller can gate on it
+/// under a [`SeverityPolicy`](crate::parser::SeverityPolicy).
 pub fn show_migration_changes(
-    migrations: &MigrationCollection,
-    direction: &MigrationDirection
-) -> () {
+    migrations: &[(i64, PathBuf, ResourceCollection, bool)],
+    direction: &MigrationDirection,
+    json: bool,
+    parse_errors: &[ParseError],
+    replaceable_schema: &ResourceCollection,
+) -> Severity {
     let operation = direction.noun();
-    let mut output = "Generating migration plan...\n--- Migration plan ---".to_string();
 
-    for (version_id, migration) in migrations.iter() {
-        let resources = migration.resources();
+    let replaceable_schema: Vec<ResourcePlanEntry> = replaceable_schema.iter().map(|resource| {
+        ResourcePlanEntry {
+            object_type: resource.object_type.to_string(),
+            name_before: resource.name_before.clone(),
+            name_after: resource.name_after.clone(),
+            statements: resource.statements.clone(),
+            severity: resource.severity(),
+        }
+    }).collect();
+
+    let plan = MigrationPlan {
+        direction: operation.to_string(),
+        migrations: migrations.iter().map(|(version_id, path, resources, no_transaction)| {
+            let resources: Vec<ResourcePlanEntry> = resources.iter().map(|resource| {
+                ResourcePlanEntry {
+                    object_type: resource.object_type.to_string(),
+                    name_before: resource.name_before.clone(),
+                    name_after: resource.name_after.clone(),
+                    statements: resource.statements.clone(),
+                    severity: resource.severity(),
+                }
+            }).collect();
+
+            let destructive = resources.iter().any(|r| r.severity == Severity::Destructive);
+
+            MigrationPlanEntry {
+                version_id: *version_id,
+                path: path.display().to_string(),
+                resources,
+                destructive,
+                no_transaction: *no_transaction,
+            }
+        }).collect(),
+        unparsed_statements: parse_errors.iter().map(|error| match &error.kind {
+            ParseErrorKind::Tokens(tokens) => tokens.iter().map(|token| token.to_string()).collect::<String>(),
+            ParseErrorKind::Statement(statement) => statement.to_string(),
+            _ => error.to_string(),
+        }).collect(),
+        replaceable_schema,
+    };
+
+    let highest_severity = plan.migrations.iter()
+        .flat_map(|entry| entry.resources.iter())
+        .chain(plan.replaceable_schema.iter())
+        .map(|resource| resource.severity)
+        .max()
+        .unwrap_or(Severity::Safe);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&plan).unwrap());
+        return highest_severity;
+    }
+
+    let mut output = "Generating migration plan...\n--- Migration plan ---".to_string();
 
-        // writeln! appends to the String
+    for entry in &plan.migrations {
         writeln!(
             &mut output,
-            "\n---\n{} {}: '{}' -> {:?} change(s)",
+            "\n---\n{} {}: '{}' -> {} change(s)",
             operation,
-            version_id,
-            migration.path.display(),
-            resources,
+            entry.version_id,
+            entry.path,
+            entry.resources.len(),
         ).unwrap();
 
-        let mut destructive_found = false;
-
-        for Resource { object_type, name_before, name_after, statements } in resources.iter() {
-            let object_name = if name_before != "-1" { name_before } else {
-                if name_after != "-1" { name_after } else {
-                    "NULL"
-                }
+        for resource in &entry.resources {
+            let object_name = if resource.name_before != "-1" {
+                resource.name_before.as_str()
+            } else if resource.name_after != "-1" {
+                resource.name_after.as_str()
+            } else {
+                "NULL"
             };
-            
-            writeln!(
-                &mut output,
-                "-> {} {}:",
-                // name_after,
-                object_type,
-                object_name,
-            ).unwrap();
 
-            for stmt in statements {
-                writeln!(
-                    &mut output,
-                    "\t-> {}",
-                    stmt,
-                ).unwrap();
-                
-                // Check for destructive statements
-                if stmt == "DROP" {
-                    destructive_found = true;
-                }
-            }
+            writeln!(&mut output, "-> {} {}:", resource.object_type, object_name).unwrap();
 
+            for stmt in &resource.statements {
+                writeln!(&mut output, "\t-> {}", stmt).unwrap();
+            }
         }
 
-        if destructive_found {
-            tracing::warn!("{} {} contains destructive actions!", operation, version_id);
+        if entry.destructive {
+            tracing::warn!("{} {} contains destructive actions!", operation, entry.version_id);
             writeln!(
                 &mut output,
                 "\n\tWARNING: {} {} contains destructive actions!",
                 operation,
-                version_id
+                entry.version_id,
+            ).unwrap();
+        }
+
+        if entry.no_transaction {
+            writeln!(
+                &mut output,
+                "\n\tNOTE: {} {} runs outside the shared transaction (no-transaction annotation).",
+                operation,
+                entry.version_id,
             ).unwrap();
         }
     }
 
+    if !plan.replaceable_schema.is_empty() {
+        writeln!(&mut output, "\n---\nReplaceable schema: {} change(s)", plan.replaceable_schema.len()).unwrap();
+
+        for resource in &plan.replaceable_schema {
+            let object_name = if resource.name_before != "-1" {
+                resource.name_before.as_str()
+            } else if resource.name_after != "-1" {
+                resource.name_after.as_str()
+            } else {
+                "NULL"
+            };
+
+            writeln!(&mut output, "-> {} {}:", resource.object_type, object_name).unwrap();
+
+            for stmt in &resource.statements {
+                writeln!(&mut output, "\t-> {}", stmt).unwrap();
+            }
+        }
+    }
+
+    if !plan.unparsed_statements.is_empty() {
+        writeln!(
+            &mut output,
+            "\n\tWARNING: {} statement(s) could not be analyzed and are missing from this plan:",
+            plan.unparsed_statements.len(),
+        ).unwrap();
+
+        for statement in &plan.unparsed_statements {
+            writeln!(&mut output, "\t-> {}", statement).unwrap();
+        }
+    }
+
     tracing::info!("{}\n--- End of migration plan ---", output);
-}
\ No newline at end of file
+
+    highest_severity
+}
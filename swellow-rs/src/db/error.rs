@@ -1,5 +1,6 @@
 use std::error::Error;
 use std::fmt;
+use std::path::PathBuf;
 
 use arrow::datatypes::DataType;
 
@@ -24,13 +25,49 @@ impl Error for EngineError {
 
 #[derive(Debug)]
 pub enum EngineErrorKind {
+    /// Every already-applied version whose `up.sql` no longer matches its
+    /// stored checksum, collected from a single `plan()` pass - reported
+    /// together so a user sees the full extent of the drift in one run
+    /// instead of aborting at the first mismatch found.
+    DriftedMigrations(Vec<(i64, PathBuf, String, String)>),
+    DownChecksumMismatch {
+        version_id: i64,
+        expected: String,
+        found: String,
+    },
     ColumnTypeMismatch {
         column_index: usize,
         expected: &'static str,
         found: DataType,
     },
+    InvalidDsn {
+        reason: String,
+    },
+    UnsupportedProtocol {
+        scheme: String,
+        expected: Vec<&'static str>,
+    },
     LockConflict,
+    /// A migration script failed partway through on an engine that can't
+    /// atomically roll back (see [`super::EngineBackend::supports_atomic_rollback`]),
+    /// so some of the script's statements may already be committed.
+    PartialMigration {
+        version_id: i64,
+        reason: String,
+    },
     PGDump(Vec<u8>),
+    PostgresPool(deadpool_postgres::PoolError),
+    /// A migration script failed on one specific statement rather than the
+    /// whole file - see [`super::EngineBackend::execute_sql_script`], which
+    /// runs a script's statements one at a time specifically so a failure
+    /// can be attributed to the statement that caused it.
+    StatementFailed {
+        statement_index: usize,
+        statement: String,
+        reason: String,
+    },
+    PostgresPoolCreate(deadpool_postgres::CreatePoolError),
+    PostgresTls(native_tls::Error),
     Process{ source: std::io::Error, cmd: String },
     Spark(spark_connect::SparkError),
     SQLX(sqlx::Error),
@@ -39,11 +76,42 @@ pub enum EngineErrorKind {
 impl fmt::Display for EngineErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::DownChecksumMismatch { version_id, expected, found } => {
+                write!(f, "down.sql checksum mismatch for version {}: expected {}, found {} (rollback script was modified after being applied)", version_id, expected, found)
+            },
+            Self::DriftedMigrations(entries) => {
+                writeln!(f, "{} applied migration(s) were modified after being applied:", entries.len())?;
+                for (version_id, path, expected, found) in entries {
+                    writeln!(f, "  - version {} ({}): expected {}, found {}", version_id, path.display(), expected, found)?;
+                }
+                Ok(())
+            },
             Self::ColumnTypeMismatch { column_index, expected, found } => {
                 write!(f, "Column {} has mismatched type: expected {}, found {:?}", column_index, expected, found)
             },
+            Self::InvalidDsn { reason } => write!(f, "Malformed connection string: {}", reason),
+            Self::UnsupportedProtocol { scheme, expected } => {
+                write!(f, "Unsupported protocol '{}': expected one of {:?}", scheme, expected)
+            },
             Self::LockConflict => write!(f, "Lock acquisition failed - lock record is taken"),
+            Self::PartialMigration { version_id, reason } => {
+                write!(
+                    f,
+                    "Version {} failed partway through and this engine has no transaction to roll back - \
+                    some of its statements may already be applied. Inspect swellow_records before retrying. \
+                    Underlying error: {}",
+                    version_id, reason
+                )
+            },
             Self::PGDump(stderr) => write!(f, "pg_dump failed: '{stderr:?}'"),
+            Self::StatementFailed { statement_index, statement, reason } => write!(
+                f,
+                "Statement {} of the migration script failed: {}\n{}",
+                statement_index + 1, reason, statement,
+            ),
+            Self::PostgresPool(e) => write!(f, "Failed to check out a Postgres connection: {e}"),
+            Self::PostgresPoolCreate(e) => write!(f, "Failed to create Postgres connection pool: {e}"),
+            Self::PostgresTls(e) => write!(f, "Failed to configure Postgres TLS: {e}"),
             Self::Process{cmd, .. } => write!(f, "Failed to run a command: '{cmd}'"),
             Self::SQLX(e) => write!(f, "{e}"),
             Self::Spark(e) => write!(f, "{e}"),
@@ -54,6 +122,9 @@ impl fmt::Display for EngineErrorKind {
 impl Error for EngineErrorKind {
 	fn source(&self) -> Option<&(dyn Error + 'static)> {
 		match self {
+			Self::PostgresPool(source) => Some(source),
+			Self::PostgresPoolCreate(source) => Some(source),
+			Self::PostgresTls(source) => Some(source),
 			Self::Process { source, .. } => Some(source),
 			Self::SQLX(source) => Some(source),
 			Self::Spark(source) => Some(source),
@@ -74,6 +145,24 @@ impl From<spark_connect::SparkError> for EngineError {
     }
 }
 
+impl From<deadpool_postgres::PoolError> for EngineError {
+    fn from(error: deadpool_postgres::PoolError) -> Self {
+        EngineError { kind: EngineErrorKind::PostgresPool(error) }
+    }
+}
+
+impl From<deadpool_postgres::CreatePoolError> for EngineError {
+    fn from(error: deadpool_postgres::CreatePoolError) -> Self {
+        EngineError { kind: EngineErrorKind::PostgresPoolCreate(error) }
+    }
+}
+
+impl From<native_tls::Error> for EngineError {
+    fn from(error: native_tls::Error) -> Self {
+        EngineError { kind: EngineErrorKind::PostgresTls(error) }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,27 +1,104 @@
+mod dsn;
 mod error;
 mod spark;
 mod postgres;
+mod sqlite;
+mod mysql;
+pub use dsn::Dsn;
 pub use error::{EngineError, EngineErrorKind};
-pub use postgres::PostgresEngine;
+pub use postgres::{create_database, PostgresEngine, SslMode, TlsConfig};
 pub use spark::{SparkEngine, SparkCatalog};
+pub use sqlite::SqliteEngine;
+pub use mysql::MySqlEngine;
 
-use crate::migration::MigrationDirection;
-
+use crate::parser::StatementCollection;
 use sqlparser;
 
+/// Postgres locking strategy for [`DbEngine::acquire_lock`]. Every other
+/// engine ignores this and uses its own native locking scheme.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum LockMode {
+    #[default]
+    Table,
+    Advisory,
+}
+
+/// How long a `swellow_locks` lease is valid for before another owner may
+/// steal it, in seconds. Refreshed by [`DbEngine::heartbeat`] while a
+/// migration is in progress.
+pub const LOCK_LEASE_SECONDS: i64 = 60;
+
+/// Fixed namespace hashed by [`DbEngine::acquire_xact_lock`]'s
+/// `pg_advisory_xact_lock` call. Unlike [`LockMode::Advisory`]'s per-database
+/// key, this one is shared crate-wide on purpose - it serializes whole
+/// migration runs against each other rather than just `swellow_records`
+/// access, so it doesn't matter which database a given run targets.
+pub const XACT_LOCK_KEY: &str = "swellow:migration-run";
+
+/// Typed `swellow_records.status` values written via [`DbEngine::update_record`].
+/// Replaces the former bare `&str` status so a crashed migrator leaves a
+/// record in an unambiguous `Failed` state instead of stuck on whatever
+/// string the last successful write happened to set.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RecordStatus {
+    Running,
+    Applied,
+    RolledBack,
+    Failed,
+}
+
+impl RecordStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecordStatus::Running => "RUNNING",
+            RecordStatus::Applied => "APPLIED",
+            RecordStatus::RolledBack => "ROLLED_BACK",
+            RecordStatus::Failed => "FAILED",
+        }
+    }
+}
+
+impl std::fmt::Display for RecordStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 
 pub enum EngineBackend {
     Postgres(PostgresEngine),
     SparkDelta(SparkEngine),
     SparkIceberg(SparkEngine),
+    Sqlite(SqliteEngine),
+    MySql(MySqlEngine),
 }
 
 impl EngineBackend {
+    /// Whether [`Self::rollback`]/[`Self::commit`] correspond to a real
+    /// transaction that a failed or dry-run migration can be undone by.
+    /// Spark SQL has no such construct at all - its `rollback`/`commit` are
+    /// no-ops. MySQL has transactions, but `ALTER`/`CREATE`/`DROP` (almost
+    /// everything a migration does) triggers an implicit commit regardless -
+    /// see [`mysql::MySqlEngine::execute_standalone`] - so a migration that
+    /// fails partway through may leave some of its DDL applied for good, and
+    /// a dry run would apply it for good too. Callers use this to skip
+    /// executing entirely on a dry run, and to report a mid-script failure
+    /// clearly instead of implying an all-or-nothing failure (see
+    /// [`EngineErrorKind::PartialMigration`]).
+    pub fn supports_atomic_rollback(&self) -> bool {
+        match self {
+            EngineBackend::Postgres(_) | EngineBackend::Sqlite(_) => true,
+            EngineBackend::SparkDelta(_) | EngineBackend::SparkIceberg(_) | EngineBackend::MySql(_) => false,
+        }
+    }
+
     pub async fn ensure_table(&self) -> Result<(), EngineError> {
         match self {
             EngineBackend::Postgres(engine) => engine.ensure_table().await,
             EngineBackend::SparkDelta(engine) => engine.ensure_table().await,
             EngineBackend::SparkIceberg(engine) => engine.ensure_table().await,
+            EngineBackend::Sqlite(engine) => engine.ensure_table().await,
+            EngineBackend::MySql(engine) => engine.ensure_table().await,
         }
     }
 
@@ -30,6 +107,8 @@ impl EngineBackend {
             EngineBackend::Postgres(engine) => engine.begin().await,
             EngineBackend::SparkDelta(engine) => engine.begin().await,
             EngineBackend::SparkIceberg(engine) => engine.begin().await,
+            EngineBackend::Sqlite(engine) => engine.begin().await,
+            EngineBackend::MySql(engine) => engine.begin().await,
         }
     }
 
@@ -38,14 +117,32 @@ impl EngineBackend {
             EngineBackend::Postgres(engine) => engine.fetch_optional_i64(sql).await,
             EngineBackend::SparkDelta(engine) => engine.fetch_optional_i64(sql).await,
             EngineBackend::SparkIceberg(engine) => engine.fetch_optional_i64(sql).await,
+            EngineBackend::Sqlite(engine) => engine.fetch_optional_i64(sql).await,
+            EngineBackend::MySql(engine) => engine.fetch_optional_i64(sql).await,
+        }
+    }
+
+    pub async fn acquire_lock(&mut self, lock_mode: LockMode) -> Result<(), EngineError> {
+        match self {
+            EngineBackend::Postgres(engine) => engine.acquire_lock(lock_mode).await,
+            EngineBackend::SparkDelta(engine) => engine.acquire_lock(lock_mode).await,
+            EngineBackend::SparkIceberg(engine) => engine.acquire_lock(lock_mode).await,
+            EngineBackend::Sqlite(engine) => engine.acquire_lock(lock_mode).await,
+            EngineBackend::MySql(engine) => engine.acquire_lock(lock_mode).await,
         }
     }
 
-    pub async fn acquire_lock(&mut self) -> Result<(), EngineError> {
+    /// Serializes whole migration runs (as opposed to [`Self::acquire_lock`],
+    /// which only guards `swellow_records` access) against each other. A
+    /// no-op on every engine but Postgres, which takes a transaction-scoped
+    /// advisory lock that's released automatically on commit/rollback.
+    pub async fn acquire_xact_lock(&mut self, no_wait: bool) -> Result<(), EngineError> {
         match self {
-            EngineBackend::Postgres(engine) => engine.acquire_lock().await,
-            EngineBackend::SparkDelta(engine) => engine.acquire_lock().await,
-            EngineBackend::SparkIceberg(engine) => engine.acquire_lock().await,
+            EngineBackend::Postgres(engine) => engine.acquire_xact_lock(no_wait).await,
+            EngineBackend::SparkDelta(engine) => engine.acquire_xact_lock(no_wait).await,
+            EngineBackend::SparkIceberg(engine) => engine.acquire_xact_lock(no_wait).await,
+            EngineBackend::Sqlite(engine) => engine.acquire_xact_lock(no_wait).await,
+            EngineBackend::MySql(engine) => engine.acquire_xact_lock(no_wait).await,
         }
     }
 
@@ -54,6 +151,44 @@ impl EngineBackend {
             EngineBackend::Postgres(engine) => engine.disable_records(current_version_id).await,
             EngineBackend::SparkDelta(engine) => engine.disable_records(current_version_id).await,
             EngineBackend::SparkIceberg(engine) => engine.disable_records(current_version_id).await,
+            EngineBackend::Sqlite(engine) => engine.disable_records(current_version_id).await,
+            EngineBackend::MySql(engine) => engine.disable_records(current_version_id).await,
+        }
+    }
+
+    /// As [`Self::disable_records`], but disables an explicit, possibly
+    /// non-contiguous set of versions (e.g. after a partial rollback)
+    /// instead of everything past a single cutoff. An empty `version_ids` is
+    /// a no-op - it must never be mistaken for "disable everything".
+    pub async fn disable_records_in(&mut self, version_ids: &[i64]) -> Result<(), EngineError> {
+        if version_ids.is_empty() {
+            return Ok(());
+        }
+
+        match self {
+            EngineBackend::Postgres(engine) => engine.disable_records_in(version_ids).await,
+            EngineBackend::SparkDelta(engine) => engine.disable_records_in(version_ids).await,
+            EngineBackend::SparkIceberg(engine) => engine.disable_records_in(version_ids).await,
+            EngineBackend::Sqlite(engine) => engine.disable_records_in(version_ids).await,
+            EngineBackend::MySql(engine) => engine.disable_records_in(version_ids).await,
+        }
+    }
+
+    /// Of `version_ids`, returns the subset that actually has a
+    /// `swellow_records` row - the read-side counterpart to
+    /// [`Self::disable_records_in`], used to flag an unknown version in an
+    /// explicit `--versions` list before disabling anything.
+    pub async fn fetch_records_in(&mut self, version_ids: &[i64]) -> Result<Vec<i64>, EngineError> {
+        if version_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        match self {
+            EngineBackend::Postgres(engine) => engine.fetch_records_in(version_ids).await,
+            EngineBackend::SparkDelta(engine) => engine.fetch_records_in(version_ids).await,
+            EngineBackend::SparkIceberg(engine) => engine.fetch_records_in(version_ids).await,
+            EngineBackend::Sqlite(engine) => engine.fetch_records_in(version_ids).await,
+            EngineBackend::MySql(engine) => engine.fetch_records_in(version_ids).await,
         }
     }
 
@@ -87,6 +222,65 @@ impl EngineBackend {
                 version_id,
                 checksum,
             ).await,
+            EngineBackend::Sqlite(engine) => engine.upsert_record(
+                object_type,
+                object_name_before,
+                object_name_after,
+                version_id,
+                checksum,
+            ).await,
+            EngineBackend::MySql(engine) => engine.upsert_record(
+                object_type,
+                object_name_before,
+                object_name_after,
+                version_id,
+                checksum,
+            ).await,
+        }
+    }
+
+    pub async fn fetch_checksum(&mut self, version_id: i64) -> Result<Option<String>, EngineError> {
+        match self {
+            EngineBackend::Postgres(engine) => engine.fetch_checksum(version_id).await,
+            EngineBackend::SparkDelta(engine) => engine.fetch_checksum(version_id).await,
+            EngineBackend::SparkIceberg(engine) => engine.fetch_checksum(version_id).await,
+            EngineBackend::Sqlite(engine) => engine.fetch_checksum(version_id).await,
+            EngineBackend::MySql(engine) => engine.fetch_checksum(version_id).await,
+        }
+    }
+
+    /// Fetch the stored `down.sql` checksum for a version, tracked separately
+    /// from [`Self::fetch_checksum`] (see [`DbEngine::upsert_down_checksum`]).
+    pub async fn fetch_down_checksum(&mut self, version_id: i64) -> Result<Option<String>, EngineError> {
+        match self {
+            EngineBackend::Postgres(engine) => engine.fetch_down_checksum(version_id).await,
+            EngineBackend::SparkDelta(engine) => engine.fetch_down_checksum(version_id).await,
+            EngineBackend::SparkIceberg(engine) => engine.fetch_down_checksum(version_id).await,
+            EngineBackend::Sqlite(engine) => engine.fetch_down_checksum(version_id).await,
+            EngineBackend::MySql(engine) => engine.fetch_down_checksum(version_id).await,
+        }
+    }
+
+    /// Records `checksum` as the `down.sql` checksum for `version_id`, so a
+    /// later run can detect a rollback script edited after the version was
+    /// applied (see [`Self::fetch_down_checksum`]).
+    pub async fn upsert_down_checksum(&mut self, version_id: i64, checksum: &str) -> Result<(), EngineError> {
+        match self {
+            EngineBackend::Postgres(engine) => engine.upsert_down_checksum(version_id, checksum).await,
+            EngineBackend::SparkDelta(engine) => engine.upsert_down_checksum(version_id, checksum).await,
+            EngineBackend::SparkIceberg(engine) => engine.upsert_down_checksum(version_id, checksum).await,
+            EngineBackend::Sqlite(engine) => engine.upsert_down_checksum(version_id, checksum).await,
+            EngineBackend::MySql(engine) => engine.upsert_down_checksum(version_id, checksum).await,
+        }
+    }
+
+    pub async fn fetch_applied_versions(&mut self) -> Result<Vec<i64>, EngineError> {
+        match self {
+            EngineBackend::Postgres(engine) => engine.fetch_applied_versions().await,
+            EngineBackend::SparkDelta(engine) => engine.fetch_applied_versions().await,
+            EngineBackend::SparkIceberg(engine) => engine.fetch_applied_versions().await,
+            EngineBackend::Sqlite(engine) => engine.fetch_applied_versions().await,
+            EngineBackend::MySql(engine) => engine.fetch_applied_versions().await,
         }
     }
 
@@ -95,25 +289,63 @@ impl EngineBackend {
             EngineBackend::Postgres(engine) => engine.execute(sql).await?,
             EngineBackend::SparkDelta(engine) => engine.execute(sql).await?,
             EngineBackend::SparkIceberg(engine) => engine.execute(sql).await?,
+            EngineBackend::Sqlite(engine) => engine.execute(sql).await?,
+            EngineBackend::MySql(engine) => engine.execute(sql).await?,
+        }
+
+        Ok(())
+    }
+
+    /// As [`Self::execute`], but outside whatever transaction [`Self::begin`]
+    /// opened on `self` - see [`crate::migrations::directory::NO_TRANSACTION_ANNOTATION`].
+    pub async fn execute_standalone(&mut self, sql: &str) -> Result<(), EngineError> {
+        match self {
+            EngineBackend::Postgres(engine) => engine.execute_standalone(sql).await?,
+            EngineBackend::SparkDelta(engine) => engine.execute_standalone(sql).await?,
+            EngineBackend::SparkIceberg(engine) => engine.execute_standalone(sql).await?,
+            EngineBackend::Sqlite(engine) => engine.execute_standalone(sql).await?,
+            EngineBackend::MySql(engine) => engine.execute_standalone(sql).await?,
         }
 
         Ok(())
     }
 
     pub async fn update_record(
-        &mut self, 
-        direction: &MigrationDirection,
+        &mut self,
+        status: RecordStatus,
         version_id: i64
     ) -> Result<(), EngineError> {
-        let status = match direction {
-            MigrationDirection::Up => "APPLIED",
-            MigrationDirection::Down => "ROLLED_BACK"
-        };
-
         match self {
             EngineBackend::Postgres(engine) => engine.update_record(status, version_id).await,
             EngineBackend::SparkDelta(engine) => engine.update_record(status, version_id).await,
             EngineBackend::SparkIceberg(engine) => engine.update_record(status, version_id).await,
+            EngineBackend::Sqlite(engine) => engine.update_record(status, version_id).await,
+            EngineBackend::MySql(engine) => engine.update_record(status, version_id).await,
+        }
+    }
+
+    /// Extends the caller's `swellow_locks` lease by [`LOCK_LEASE_SECONDS`].
+    /// Call this at a steady cadence while a migration is running so a lease
+    /// stolen mid-run (because the lock's owner went silent) is the
+    /// exception, not the norm.
+    pub async fn heartbeat(&mut self) -> Result<(), EngineError> {
+        match self {
+            EngineBackend::Postgres(engine) => engine.heartbeat().await,
+            EngineBackend::SparkDelta(engine) => engine.heartbeat().await,
+            EngineBackend::SparkIceberg(engine) => engine.heartbeat().await,
+            EngineBackend::Sqlite(engine) => engine.heartbeat().await,
+            EngineBackend::MySql(engine) => engine.heartbeat().await,
+        }
+    }
+
+    /// Clears this instance's `swellow_locks` lease, if it holds one.
+    pub async fn release_lock(&mut self) -> Result<(), EngineError> {
+        match self {
+            EngineBackend::Postgres(engine) => engine.release_lock().await,
+            EngineBackend::SparkDelta(engine) => engine.release_lock().await,
+            EngineBackend::SparkIceberg(engine) => engine.release_lock().await,
+            EngineBackend::Sqlite(engine) => engine.release_lock().await,
+            EngineBackend::MySql(engine) => engine.release_lock().await,
         }
     }
 
@@ -122,6 +354,8 @@ impl EngineBackend {
             EngineBackend::Postgres(engine) => engine.rollback().await,
             EngineBackend::SparkDelta(engine) => engine.rollback().await,
             EngineBackend::SparkIceberg(engine) => engine.rollback().await,
+            EngineBackend::Sqlite(engine) => engine.rollback().await,
+            EngineBackend::MySql(engine) => engine.rollback().await,
         }
     }
 
@@ -130,6 +364,8 @@ impl EngineBackend {
             EngineBackend::Postgres(engine) => engine.commit().await,
             EngineBackend::SparkDelta(engine) => engine.commit().await,
             EngineBackend::SparkIceberg(engine) => engine.commit().await,
+            EngineBackend::Sqlite(engine) => engine.commit().await,
+            EngineBackend::MySql(engine) => engine.commit().await,
         }
     }
 
@@ -138,7 +374,67 @@ impl EngineBackend {
             EngineBackend::Postgres(engine) => engine.snapshot(),
             EngineBackend::SparkDelta(engine) => engine.snapshot(),
             EngineBackend::SparkIceberg(engine) => engine.snapshot(),
+            EngineBackend::Sqlite(engine) => engine.snapshot(),
+            EngineBackend::MySql(engine) => engine.snapshot(),
+        }
+    }
+
+    /// Executes a migration script one statement at a time (in file order,
+    /// inside whatever transaction [`Self::begin`] already opened) instead
+    /// of handing the whole file to the driver as a single multi-statement
+    /// call - so a table created by an earlier statement is visible to a
+    /// later one regardless of how the driver would otherwise batch a blob
+    /// of SQL, and a failure points at the specific statement (see
+    /// [`EngineErrorKind::StatementFailed`]) instead of the whole file.
+    pub async fn execute_sql_script(&mut self, file_path: &std::path::Path) -> anyhow::Result<()> {
+        let sql = std::fs::read_to_string(file_path)?;
+        let statements = StatementCollection::from_backend(self).parse_sql(&sql).to_strings();
+
+        for (index, statement) in statements.iter().enumerate() {
+            if statement.trim().is_empty() {
+                continue;
+            }
+
+            if let Err(e) = self.execute(statement).await {
+                return Err(EngineError {
+                    kind: EngineErrorKind::StatementFailed {
+                        statement_index: index,
+                        statement: statement.clone(),
+                        reason: e.to_string(),
+                    },
+                }.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// As [`Self::execute_sql_script`], but via [`Self::execute_standalone`]
+    /// instead of [`Self::execute`] - for a migration flagged
+    /// [`crate::migrations::directory::NO_TRANSACTION_ANNOTATION`], whose
+    /// statements (e.g. Postgres `CREATE INDEX CONCURRENTLY`) would error
+    /// inside the shared transaction [`Self::begin`] already opened.
+    pub async fn execute_standalone_script(&mut self, file_path: &std::path::Path) -> anyhow::Result<()> {
+        let sql = std::fs::read_to_string(file_path)?;
+        let statements = StatementCollection::from_backend(self).parse_sql(&sql).to_strings();
+
+        for (index, statement) in statements.iter().enumerate() {
+            if statement.trim().is_empty() {
+                continue;
+            }
+
+            if let Err(e) = self.execute_standalone(statement).await {
+                return Err(EngineError {
+                    kind: EngineErrorKind::StatementFailed {
+                        statement_index: index,
+                        statement: statement.clone(),
+                        reason: e.to_string(),
+                    },
+                }.into());
+            }
         }
+
+        Ok(())
     }
 }
 
@@ -147,9 +443,45 @@ pub trait DbEngine {
     async fn ensure_table(&self) -> Result<(), EngineError>;
     async fn begin(&mut self) -> Result<(), EngineError>;
     async fn execute(&mut self, sql: &str) -> Result<(), EngineError>;
+    /// As [`Self::execute`], but outside whatever transaction [`Self::begin`]
+    /// opened - see [`crate::migrations::directory::NO_TRANSACTION_ANNOTATION`].
+    /// Engines without a meaningful transaction to escape (everything but
+    /// Postgres) just delegate to [`Self::execute`].
+    async fn execute_standalone(&mut self, sql: &str) -> Result<(), EngineError>;
     async fn fetch_optional_i64(&mut self, sql: &str) -> Result<Option<i64>, EngineError>;
-    async fn acquire_lock(&mut self) -> Result<(), EngineError>;
+    /// Fetch the stored `up.sql` checksum (see [`file_checksum`])
+    /// for a single migration record, used to detect drift against the on-disk file.
+    async fn fetch_checksum(&mut self, version_id: i64) -> Result<Option<String>, EngineError>;
+    /// Fetch the stored `down.sql` checksum for `version_id`, tracked
+    /// separately from [`Self::fetch_checksum`]'s `up.sql` checksum since the
+    /// two files drift independently.
+    async fn fetch_down_checksum(&mut self, version_id: i64) -> Result<Option<String>, EngineError>;
+    /// Records `checksum` as the `down.sql` checksum for `version_id`. Unlike
+    /// [`Self::upsert_record`], this isn't keyed per-resource - `down.sql`
+    /// isn't parsed into a [`crate::parser::ResourceCollection`], so one row
+    /// per version is enough.
+    async fn upsert_down_checksum(&mut self, version_id: i64, checksum: &str) -> Result<(), EngineError>;
+    /// Lists every version ID currently recorded as `APPLIED`/`TESTED`, so
+    /// drift can be checked across the whole migration history instead of
+    /// just the range targeted by the current `up`/`down` run.
+    async fn fetch_applied_versions(&mut self) -> Result<Vec<i64>, EngineError>;
+    async fn acquire_lock(&mut self, lock_mode: LockMode) -> Result<(), EngineError>;
+    /// Serializes whole migration runs against each other, independent of
+    /// [`Self::acquire_lock`]'s `swellow_records` guard. Only meaningful on
+    /// Postgres, where it's a transaction-scoped advisory lock released
+    /// automatically on commit/rollback; every other engine treats this as a
+    /// no-op. When `no_wait` is set, fail immediately with
+    /// [`EngineErrorKind::LockConflict`] instead of waiting for the lock.
+    async fn acquire_xact_lock(&mut self, no_wait: bool) -> Result<(), EngineError>;
     async fn disable_records(&mut self, current_version_id: i64) -> Result<(), EngineError>;
+    /// As [`Self::disable_records`], but for an explicit, possibly
+    /// non-contiguous set of versions. Never called with an empty slice -
+    /// [`EngineBackend::disable_records_in`] short-circuits that case.
+    async fn disable_records_in(&mut self, version_ids: &[i64]) -> Result<(), EngineError>;
+    /// Of `version_ids`, returns the subset that has a `swellow_records`
+    /// row. Never called with an empty slice - see
+    /// [`Self::disable_records_in`].
+    async fn fetch_records_in(&mut self, version_ids: &[i64]) -> Result<Vec<i64>, EngineError>;
     async fn upsert_record(
         &mut self,
         object_type: &sqlparser::ast::ObjectType,
@@ -158,8 +490,73 @@ pub trait DbEngine {
         version_id: i64,
         checksum: &str
     ) -> Result<(), EngineError>;
-    async fn update_record(&mut self, status: &str, version_id: i64) -> Result<(), EngineError>;
+    async fn update_record(&mut self, status: RecordStatus, version_id: i64) -> Result<(), EngineError>;
+    /// Extends this instance's `swellow_locks` lease by [`LOCK_LEASE_SECONDS`].
+    async fn heartbeat(&mut self) -> Result<(), EngineError>;
+    /// Clears this instance's `swellow_locks` lease, if it holds one.
+    async fn release_lock(&mut self) -> Result<(), EngineError>;
     async fn rollback(&mut self) -> Result<(), EngineError>;
     async fn commit(&mut self) -> Result<(), EngineError>;
     fn snapshot(&mut self) -> Result<Vec<u8>, EngineError>;
 }
+
+/// SHA-256 of `path`'s raw bytes, streamed through a fixed-size buffer
+/// rather than read into memory all at once. Used for both
+/// [`DbEngine::upsert_record`]'s stored checksum and later drift checks
+/// against it - hashing the exact bytes (not a reparsed/round-tripped form
+/// of the SQL) means the comparison can't be fooled by a file that parses
+/// identically but was edited in a way that matters (e.g. a comment).
+pub(crate) fn file_checksum(path: &std::path::Path) -> std::io::Result<String> {
+    use sha2::{Digest, Sha256};
+    use std::io::Read;
+
+    let file = std::fs::File::open(path)?;
+    let mut reader = std::io::BufReader::new(file);
+
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 4096];
+
+    loop {
+        let n = reader.read(&mut buffer)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buffer[..n]);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Regression test for a bug where `supports_atomic_rollback` marked
+    /// MySQL `true`: since its DDL auto-commits outside an explicit
+    /// transaction regardless (see `mysql::MySqlEngine::execute_standalone`),
+    /// that made `--dry-run` apply migrations against MySQL for real instead
+    /// of no-op'ing. Requires a real server, so it's `#[ignore]`d by default -
+    /// run with `cargo test -- --ignored` against `MYSQL_TEST_DATABASE_URL`.
+    #[tokio::test]
+    #[ignore]
+    async fn mysql_dry_run_does_not_commit_ddl() {
+        let conn_str = std::env::var("MYSQL_TEST_DATABASE_URL")
+            .expect("set MYSQL_TEST_DATABASE_URL to run this test");
+
+        let mut backend = EngineBackend::MySql(MySqlEngine::new(&conn_str).await.unwrap());
+        assert!(!backend.supports_atomic_rollback());
+
+        backend.begin().await.unwrap();
+        backend.execute("CREATE TABLE swellow_dry_run_probe (id INT)").await.unwrap();
+        backend.rollback().await.unwrap();
+
+        let exists = backend
+            .fetch_optional_i64(
+                "SELECT 1 FROM information_schema.tables WHERE table_name = 'swellow_dry_run_probe'",
+            )
+            .await
+            .unwrap();
+
+        assert!(exists.is_none(), "CREATE TABLE survived a rolled-back dry run");
+    }
+}
@@ -0,0 +1,427 @@
+use super::{DbEngine, Dsn};
+use sqlx::{MySql, MySqlPool, Transaction};
+use std::process;
+
+pub struct MySqlEngine {
+    pool: MySqlPool,
+    conn_str: String,
+    tx: Option<Transaction<'static, MySql>>,
+    /// Identifies this instance's `swellow_locks` lease, so a heartbeat or
+    /// release only ever touches a lease this instance actually holds.
+    lease_owner: String,
+}
+
+
+impl MySqlEngine {
+    /// Builds a long-lived connection pool once, reused by every call
+    /// instead of reconnecting on each operation.
+    pub async fn new(conn_str: &str) -> anyhow::Result<Self, super::EngineError> {
+        Self::with_pool_options(conn_str, sqlx::mysql::MySqlPoolOptions::new()).await
+    }
+
+    /// As [`MySqlEngine::new`], but lets the caller size the pool.
+    pub async fn with_pool_options(
+        conn_str: &str,
+        options: sqlx::mysql::MySqlPoolOptions,
+    ) -> anyhow::Result<Self, super::EngineError> {
+        Dsn::parse(conn_str)?.validate_scheme(&["mysql"])?;
+
+        let pool = options.connect(conn_str).await?;
+
+        Ok(MySqlEngine {
+            pool,
+            conn_str: conn_str.to_string(),
+            tx: None,
+            lease_owner: uuid::Uuid::new_v4().to_string(),
+        })
+    }
+
+    async fn transaction(&mut self) -> anyhow::Result<&mut Transaction<'static, MySql>> {
+        if self.tx.is_none() {
+            let txn = self.pool.begin().await?;
+            self.tx = Some(txn);
+        }
+
+        Ok(self.tx.as_mut().unwrap())
+    }
+}
+
+
+impl DbEngine for MySqlEngine {
+    async fn ensure_table(&self) -> anyhow::Result<()> {
+        // MySQL has no OID type or pgcrypto extension - use an auto-increment
+        // surrogate key and CURRENT_TIMESTAMP in place of now().
+        sqlx::query(r#"
+            CREATE TABLE IF NOT EXISTS swellow_records (
+                oid BIGINT AUTO_INCREMENT,
+                version_id BIGINT NOT NULL,
+                object_type TEXT NOT NULL,
+                object_name_before VARCHAR(255) NOT NULL,
+                object_name_after VARCHAR(255) NOT NULL,
+                status TEXT NOT NULL,
+                checksum TEXT NOT NULL,
+                dtm_created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                dtm_updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                PRIMARY KEY (oid),
+                UNIQUE KEY swellow_records_identity (version_id, object_type, object_name_before, object_name_after)
+            );
+        "#)
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(r#"
+            CREATE TABLE IF NOT EXISTS swellow_locks (
+                id VARCHAR(255) PRIMARY KEY,
+                owner_id VARCHAR(255) NOT NULL,
+                acquired_at TIMESTAMP NOT NULL,
+                expires_at TIMESTAMP NOT NULL,
+                INDEX swellow_locks_expires_at_idx (expires_at)
+            );
+        "#)
+        .execute(&self.pool)
+        .await?;
+
+        // Tracked separately from swellow_records.checksum (which is over
+        // up.sql) so an edited down.sql is detected even though it's never
+        // parsed into per-resource records.
+        sqlx::query(r#"
+            CREATE TABLE IF NOT EXISTS swellow_down_checksums (
+                version_id BIGINT PRIMARY KEY,
+                checksum TEXT NOT NULL,
+                dtm_updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            );
+        "#)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn begin(&mut self) -> anyhow::Result<()> {
+        self.transaction().await?;
+
+        Ok(())
+    }
+
+    async fn execute(&mut self, sql: &str) -> anyhow::Result<()> {
+        let tx = self.transaction().await?;
+
+        sqlx::raw_sql(&sql)
+            .execute(&mut **tx)
+            .await?;
+
+        Ok(())
+    }
+
+    /// MySQL's DDL auto-commits outside an explicit transaction anyway, so
+    /// there's no analogous "transaction block" to escape - delegates
+    /// straight to [`Self::execute`].
+    async fn execute_standalone(&mut self, sql: &str) -> anyhow::Result<()> {
+        self.execute(sql).await
+    }
+
+    /// Fetch an optional single column value
+    async fn fetch_optional_i64(&mut self, sql: &str) -> anyhow::Result<Option<i64>> {
+        let tx = self.transaction().await?;
+
+        Ok(sqlx::query_scalar(sql)
+            .fetch_one(&mut **tx)
+            .await?)
+    }
+
+    async fn fetch_checksum(&mut self, version_id: i64) -> anyhow::Result<Option<String>> {
+        let tx = self.transaction().await?;
+
+        Ok(sqlx::query_scalar(
+            "SELECT checksum FROM swellow_records WHERE version_id = ? LIMIT 1",
+        )
+            .bind(version_id)
+            .fetch_optional(&mut **tx)
+            .await?)
+    }
+
+    async fn fetch_down_checksum(&mut self, version_id: i64) -> anyhow::Result<Option<String>> {
+        let tx = self.transaction().await?;
+
+        Ok(sqlx::query_scalar(
+            "SELECT checksum FROM swellow_down_checksums WHERE version_id = ?",
+        )
+            .bind(version_id)
+            .fetch_optional(&mut **tx)
+            .await?)
+    }
+
+    async fn upsert_down_checksum(&mut self, version_id: i64, checksum: &str) -> anyhow::Result<()> {
+        let tx = self.transaction().await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO swellow_down_checksums(version_id, checksum)
+            VALUES (?, ?)
+            ON DUPLICATE KEY UPDATE
+                checksum = VALUES(checksum),
+                dtm_updated_at = CURRENT_TIMESTAMP
+            "#,
+        )
+            .bind(version_id)
+            .bind(checksum)
+            .execute(&mut **tx)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn fetch_applied_versions(&mut self) -> anyhow::Result<Vec<i64>> {
+        let tx = self.transaction().await?;
+
+        Ok(sqlx::query_scalar(
+            "SELECT DISTINCT version_id FROM swellow_records WHERE status IN ('APPLIED', 'TESTED') ORDER BY version_id",
+        )
+            .fetch_all(&mut **tx)
+            .await?)
+    }
+
+    // Session-level advisory lock rather than `LOCK TABLES`, which would
+    // persist on the pooled connection beyond this transaction and stall
+    // whichever later caller borrows it from the pool next.
+    async fn acquire_lock(&mut self, _lock_mode: super::LockMode) -> anyhow::Result<()> {
+        let tx = self.transaction().await?;
+
+        let acquired: Option<i32> = sqlx::query_scalar("SELECT GET_LOCK('swellow_records', 10)")
+            .fetch_one(&mut **tx)
+            .await?;
+
+        if acquired != Some(1) {
+            anyhow::bail!("Failed to acquire MySQL lock 'swellow_records' (already held elsewhere)");
+        }
+
+        // GET_LOCK() is released the instant a crashed process' connection
+        // drops, leaving no record of who held it or for how long. The lease
+        // row below is the crash-safe complement: refused only if another
+        // owner's lease hasn't expired, stolen outright once it has.
+        let existing: Option<(String, bool)> = sqlx::query_as(
+            "SELECT owner_id, expires_at > NOW() AS alive FROM swellow_locks WHERE id = 'swellow_records'",
+        )
+            .fetch_optional(&mut **tx)
+            .await?;
+
+        if let Some((owner_id, alive)) = existing {
+            if alive && owner_id != self.lease_owner {
+                return Err(super::EngineError { kind: super::EngineErrorKind::LockConflict }.into());
+            }
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO swellow_locks (id, owner_id, acquired_at, expires_at)
+            VALUES ('swellow_records', ?, NOW(), DATE_ADD(NOW(), INTERVAL ? SECOND))
+            ON DUPLICATE KEY UPDATE
+                owner_id = VALUES(owner_id),
+                acquired_at = VALUES(acquired_at),
+                expires_at = VALUES(expires_at)
+            "#,
+        )
+            .bind(&self.lease_owner)
+            .bind(super::LOCK_LEASE_SECONDS)
+            .execute(&mut **tx)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn acquire_xact_lock(&mut self, _no_wait: bool) -> anyhow::Result<()> {
+        // GET_LOCK() in acquire_lock above already serializes concurrent
+        // swellow processes against each other, so there's nothing further
+        // to do here.
+        Ok(())
+    }
+
+    async fn heartbeat(&mut self) -> anyhow::Result<()> {
+        let tx = self.transaction().await?;
+
+        let result = sqlx::query(
+            r#"
+            UPDATE swellow_locks
+            SET expires_at = DATE_ADD(NOW(), INTERVAL ? SECOND)
+            WHERE id = 'swellow_records' AND owner_id = ?
+            "#,
+        )
+            .bind(super::LOCK_LEASE_SECONDS)
+            .bind(&self.lease_owner)
+            .execute(&mut **tx)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            anyhow::bail!("Lease for 'swellow_records' was stolen by another owner");
+        }
+
+        Ok(())
+    }
+
+    async fn release_lock(&mut self) -> anyhow::Result<()> {
+        sqlx::query("DELETE FROM swellow_locks WHERE id = 'swellow_records' AND owner_id = ?")
+            .bind(&self.lease_owner)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn disable_records(&mut self, current_version_id: i64) -> anyhow::Result<()> {
+        let tx = self.transaction().await?;
+
+        sqlx::query(
+            r#"
+            UPDATE swellow_records
+            SET status='DISABLED'
+            WHERE version_id > ?
+            "#,
+        )
+            .bind(current_version_id)
+            .execute(&mut **tx)
+            .await?;
+        Ok(())
+    }
+
+    async fn disable_records_in(&mut self, version_ids: &[i64]) -> anyhow::Result<()> {
+        // MySQL has no array parameter type either - bind one placeholder per id.
+        let placeholders = vec!["?"; version_ids.len()].join(", ");
+        let sql = format!("UPDATE swellow_records SET status='DISABLED' WHERE version_id IN ({placeholders})");
+
+        let tx = self.transaction().await?;
+        let mut query = sqlx::query(&sql);
+        for version_id in version_ids {
+            query = query.bind(version_id);
+        }
+        query.execute(&mut **tx).await?;
+
+        Ok(())
+    }
+
+    async fn fetch_records_in(&mut self, version_ids: &[i64]) -> anyhow::Result<Vec<i64>> {
+        let placeholders = vec!["?"; version_ids.len()].join(", ");
+        let sql = format!("SELECT DISTINCT version_id FROM swellow_records WHERE version_id IN ({placeholders})");
+
+        let tx = self.transaction().await?;
+        let mut query = sqlx::query_scalar(&sql);
+        for version_id in version_ids {
+            query = query.bind(version_id);
+        }
+
+        Ok(query.fetch_all(&mut **tx).await?)
+    }
+
+    async fn upsert_record(
+        &mut self,
+        object_type: &sqlparser::ast::ObjectType,
+        object_name_before: &str,
+        object_name_after: &str,
+        version_id: i64,
+        checksum: &str
+    ) -> anyhow::Result<()> {
+        let tx = self.transaction().await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO swellow_records(
+                object_type,
+                object_name_before,
+                object_name_after,
+                version_id,
+                status,
+                checksum
+            )
+            VALUES (?, ?, ?, ?, 'READY', ?)
+            ON DUPLICATE KEY UPDATE
+                status = VALUES(status),
+                checksum = VALUES(checksum)
+            "#,
+        )
+            .bind(object_type.to_string())
+            .bind(object_name_before)
+            .bind(object_name_after)
+            .bind(version_id)
+            .bind(checksum)
+            .execute(&mut **tx)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn update_record(&mut self, status: super::RecordStatus, version_id: i64) -> anyhow::Result<()> {
+        let tx = self.transaction().await?;
+
+        sqlx::query(
+            r#"
+            UPDATE swellow_records
+            SET
+                status=?
+            WHERE
+                version_id=?
+            "#,
+        )
+            .bind(status.as_str())
+            .bind(version_id)
+            .execute(&mut **tx)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn rollback(&mut self) -> anyhow::Result<()> {
+        if let Some(tx) = self.tx.take() {
+            tx.rollback().await?;
+        }
+        sqlx::query("SELECT RELEASE_LOCK('swellow_records')").execute(&self.pool).await?;
+        Ok(())
+    }
+
+    async fn commit(&mut self) -> anyhow::Result<()> {
+        if let Some(tx) = self.tx.take() {
+            tx.commit().await?;
+        }
+        sqlx::query("SELECT RELEASE_LOCK('swellow_records')").execute(&self.pool).await?;
+        Ok(())
+    }
+
+    fn snapshot(&mut self) -> anyhow::Result<Vec<u8>> {
+        // MySQL has no pg_dump equivalent binary - mysqldump --no-data
+        // produces the same "schema only" CREATE statements that pg_dump's
+        // --schema-only does for Postgres.
+        if process::Command::new("mysqldump").arg("--version").output().is_err() {
+            tracing::error!("mysqldump not installed or not in PATH.");
+            std::process::exit(1);
+        }
+
+        let dsn = Dsn::parse(&self.conn_str)?;
+
+        let mut cmd = process::Command::new("mysqldump");
+        cmd.arg("--no-data").arg("--skip-comments");
+
+        if let Some(host) = &dsn.host {
+            cmd.arg("-h").arg(host);
+        }
+        if let Some(port) = dsn.port {
+            cmd.arg("-P").arg(port.to_string());
+        }
+        if let Some(user) = &dsn.user {
+            cmd.arg("-u").arg(user);
+        }
+        if let Some(password) = &dsn.password {
+            cmd.arg(format!("-p{password}"));
+        }
+
+        match &dsn.database {
+            Some(database) => { cmd.arg(database); }
+            None => anyhow::bail!("mysqldump requires a database name in the connection string"),
+        }
+
+        let output = cmd.output()?;
+
+        if output.status.success() {
+            Ok(output.stdout)
+        } else {
+            anyhow::bail!("mysqldump error: {}", String::from_utf8_lossy(&output.stderr))
+        }
+    }
+}
@@ -1,28 +1,142 @@
-use std::ops::DerefMut;
-
-use super::{DbEngine, file_checksum};
+use super::{DbEngine, Dsn};
+use deadpool_postgres::{Object, Pool, Runtime};
+use postgres_native_tls::MakeTlsConnector;
 use sqlparser;
-use sqlx::{PgPool, Postgres, Transaction};
 use std::{path, process};
+use tokio_postgres::NoTls;
+
+/// Postgres TLS mode, mirroring libpq's `sslmode` spectrum.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum SslMode {
+    /// Never encrypt the connection.
+    #[default]
+    Disable,
+    /// Encrypt if the server supports it, but don't verify its certificate.
+    Prefer,
+    /// Require encryption, but don't verify the server's certificate.
+    Require,
+    /// Require encryption and verify the server's certificate against
+    /// [`TlsConfig::ca_certificate_pem`], but not the hostname.
+    VerifyCa,
+    /// Require encryption and verify both the server's certificate and hostname.
+    VerifyFull,
+}
+
+/// TLS configuration accepted by [`PostgresEngine::new`]/[`PostgresEngine::with_pool_config`].
+#[derive(Clone, Debug, Default)]
+pub struct TlsConfig {
+    pub mode: SslMode,
+    /// PEM-encoded CA certificate, checked when `mode` is `VerifyCa`/`VerifyFull`.
+    pub ca_certificate_pem: Option<Vec<u8>>,
+    /// A PKCS#12 bundle (certificate + private key) and its passphrase, for
+    /// mutual TLS client authentication.
+    pub client_identity_pkcs12: Option<(Vec<u8>, String)>,
+}
+
+/// Builds a `postgres-native-tls` connector from `tls`. Only called when
+/// `tls.mode` is not [`SslMode::Disable`].
+fn build_tls_connector(tls: &TlsConfig) -> anyhow::Result<MakeTlsConnector, super::EngineError> {
+    let mut builder = native_tls::TlsConnector::builder();
+
+    match tls.mode {
+        SslMode::Disable => unreachable!("a TLS connector is only built for non-Disable modes"),
+        SslMode::Prefer | SslMode::Require => {
+            builder.danger_accept_invalid_certs(true);
+            builder.danger_accept_invalid_hostnames(true);
+        }
+        SslMode::VerifyCa => {
+            builder.danger_accept_invalid_hostnames(true);
+        }
+        SslMode::VerifyFull => {}
+    }
+
+    if let Some(ca_pem) = &tls.ca_certificate_pem {
+        let ca = native_tls::Certificate::from_pem(ca_pem)
+            .map_err(|e| super::EngineError { kind: super::EngineErrorKind::PostgresTls(e) })?;
+        builder.add_root_certificate(ca);
+    }
+
+    if let Some((pkcs12, passphrase)) = &tls.client_identity_pkcs12 {
+        let identity = native_tls::Identity::from_pkcs12(pkcs12, passphrase)
+            .map_err(|e| super::EngineError { kind: super::EngineErrorKind::PostgresTls(e) })?;
+        builder.identity(identity);
+    }
+
+    let connector = builder.build()
+        .map_err(|e| super::EngineError { kind: super::EngineErrorKind::PostgresTls(e) })?;
+
+    Ok(MakeTlsConnector::new(connector))
+}
 
 pub struct PostgresEngine {
+    pool: Pool,
     conn_str: String,
-    tx: Option<Transaction<'static, Postgres>>,
+    conn: Option<Object>,
+    /// Identifies this instance's `swellow_locks` lease, so a heartbeat or
+    /// release only ever touches a lease this instance actually holds.
+    lease_owner: String,
+    /// Namespace for the `LockMode::Advisory` session lock, so two swellow
+    /// instances pointed at different databases never contend over the
+    /// same key. Hashed down to an int4 by `hashtext` at call time.
+    advisory_lock_key: String,
 }
 
 
 impl PostgresEngine {
-    pub fn new(conn_str: String) -> Self {
-        return PostgresEngine { conn_str: conn_str, tx: None }
+    /// Builds a long-lived deadpool-postgres connection pool once, reused by
+    /// every call instead of reconnecting on each operation.
+    pub async fn new(conn_str: &str, tls: TlsConfig) -> anyhow::Result<Self, super::EngineError> {
+        Self::with_pool_config(conn_str, deadpool_postgres::Config::default(), tls).await
     }
 
-    async fn transaction(&mut self) -> anyhow::Result<&mut Transaction<'static, Postgres>> {
-        if self.tx.is_none() {
-            let txn = PgPool::connect(&self.conn_str).await?.begin().await?;
-            self.tx = Some(txn);
+    /// As [`PostgresEngine::new`], but lets the caller size the pool (max
+    /// size, recycling method, timeouts) so large migration sets don't
+    /// exhaust or thrash connections.
+    pub async fn with_pool_config(
+        conn_str: &str,
+        mut pool_config: deadpool_postgres::Config,
+        tls: TlsConfig,
+    ) -> anyhow::Result<Self, super::EngineError> {
+        // Validate the DSN up-front so a malformed or non-Postgres connection
+        // string surfaces a precise EngineErrorKind instead of a late,
+        // driver-level failure from the pool.
+        let dsn = Dsn::parse(conn_str)?;
+        dsn.validate_scheme(&["postgresql", "postgres"])?;
+
+        let advisory_lock_key = format!("swellow:{}", dsn.database.as_deref().unwrap_or("swellow_records"));
+
+        pool_config.url = Some(conn_str.to_string());
+
+        let pool = if tls.mode == SslMode::Disable {
+            pool_config.create_pool(Some(Runtime::Tokio1), NoTls)?
+        } else {
+            pool_config.create_pool(Some(Runtime::Tokio1), build_tls_connector(&tls)?)?
+        };
+
+        Ok(PostgresEngine {
+            pool,
+            conn_str: conn_str.to_string(),
+            conn: None,
+            lease_owner: uuid::Uuid::new_v4().to_string(),
+            advisory_lock_key,
+        })
+    }
+
+    /// Checks out a connection from the pool (if one isn't already checked
+    /// out for this instance) and opens a transaction on it. The checked-out
+    /// object - and any lock held on it - stays with this instance past
+    /// [`DbEngine::commit`]/[`DbEngine::rollback`] and is returned to the
+    /// pool only once [`DbEngine::release_lock`] takes it.
+    async fn connection(&mut self) -> anyhow::Result<&Object> {
+        if self.conn.is_none() {
+            let conn = self.pool.get().await.map_err(|e| super::EngineError {
+                kind: super::EngineErrorKind::PostgresPool(e),
+            })?;
+            conn.batch_execute("BEGIN").await?;
+            self.conn = Some(conn);
         }
-        
-        Ok(self.tx.as_mut().unwrap())
+
+        Ok(self.conn.as_ref().unwrap())
     }
 }
 
@@ -30,13 +144,13 @@ impl PostgresEngine {
 // #[async_trait::async_trait]
 impl DbEngine for PostgresEngine {
     async fn ensure_table(&self) -> anyhow::Result<()> {
-        let pool = PgPool::connect(&self.conn_str).await?;
-        
-        sqlx::query("CREATE EXTENSION IF NOT EXISTS pgcrypto;")
-            .execute(&pool)
-            .await?;
-        
-        sqlx::query(r#"
+        let conn = self.pool.get().await.map_err(|e| super::EngineError {
+            kind: super::EngineErrorKind::PostgresPool(e),
+        })?;
+
+        conn.execute("CREATE EXTENSION IF NOT EXISTS pgcrypto;", &[]).await?;
+
+        conn.execute(r#"
             CREATE TABLE IF NOT EXISTS swellow_records (
                 oid OID,
                 version_id BIGINT NOT NULL,
@@ -49,75 +163,315 @@ impl DbEngine for PostgresEngine {
                 dtm_updated_at TIMESTAMP DEFAULT now(),
                 PRIMARY KEY (version_id, object_type, object_name_before, object_name_after)
             );
-        "#)
-        .execute(&pool)
-        .await?;
-        
+        "#, &[]).await?;
+
+        conn.execute(r#"
+            CREATE TABLE IF NOT EXISTS swellow_locks (
+                id TEXT PRIMARY KEY,
+                owner_id TEXT NOT NULL,
+                acquired_at TIMESTAMP NOT NULL,
+                expires_at TIMESTAMP NOT NULL
+            );
+        "#, &[]).await?;
+
+        // Tracked separately from swellow_records.checksum (which is over
+        // up.sql) so an edited down.sql is detected even though it's never
+        // parsed into per-resource records.
+        conn.execute(r#"
+            CREATE TABLE IF NOT EXISTS swellow_down_checksums (
+                version_id BIGINT PRIMARY KEY,
+                checksum TEXT NOT NULL,
+                dtm_updated_at TIMESTAMP DEFAULT now()
+            );
+        "#, &[]).await?;
+
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS swellow_locks_expires_at_idx ON swellow_locks (expires_at);",
+            &[],
+        ).await?;
+
         Ok(())
     }
 
     async fn begin(&mut self) -> anyhow::Result<()> {
-        self.transaction().await?;
+        // commit/rollback keep the connection checked out (see rollback), so
+        // connection() alone wouldn't open a new transaction on it.
+        match &self.conn {
+            Some(conn) => conn.batch_execute("BEGIN").await?,
+            None => {
+                self.connection().await?;
+            }
+        }
 
         Ok(())
     }
 
     async fn execute(&mut self, sql: &str) -> anyhow::Result<()> {
-        let tx = self.transaction().await?;
+        let conn = self.connection().await?;
 
-        sqlx::raw_sql(&sql)
-            .execute(&mut **tx)
-            .await?;
+        conn.batch_execute(sql).await?;
+
+        Ok(())
+    }
+
+    /// Checks out a fresh connection from the pool - deliberately not
+    /// `self.conn`, which already has [`Self::begin`]'s transaction open -
+    /// and runs `sql` on it with no `BEGIN` of its own, so statements like
+    /// `CREATE INDEX CONCURRENTLY` that Postgres refuses inside a
+    /// transaction block can still run. The connection is dropped (and
+    /// returned to the pool) as soon as the statement finishes.
+    async fn execute_standalone(&mut self, sql: &str) -> anyhow::Result<()> {
+        let conn = self.pool.get().await.map_err(|e| super::EngineError {
+            kind: super::EngineErrorKind::PostgresPool(e),
+        })?;
+
+        conn.batch_execute(sql).await?;
 
         Ok(())
     }
 
     /// Fetch an optional single column value
     async fn fetch_optional_i64(&mut self, sql: &str) -> anyhow::Result<Option<i64>> {
-        let tx = self.transaction().await?;
-        
-        Ok(sqlx::query_scalar(sql)
-            .fetch_one(&mut **tx)
-            .await?)
+        let conn = self.connection().await?;
+
+        Ok(conn.query_one(sql, &[]).await?.get::<_, Option<i64>>(0))
     }
 
-    async fn acquire_lock(&mut self) -> anyhow::Result<()> {
-        let tx = self.transaction().await?;
+    async fn fetch_checksum(&mut self, version_id: i64) -> anyhow::Result<Option<String>> {
+        let conn = self.connection().await?;
 
-        sqlx::query("LOCK TABLE swellow_records IN ACCESS EXCLUSIVE MODE;")
-            .execute(tx.deref_mut())
-            .await?;
+        Ok(conn
+            .query_opt(
+                "SELECT checksum FROM swellow_records WHERE version_id = $1 LIMIT 1",
+                &[&version_id],
+            )
+            .await?
+            .map(|row| row.get::<_, String>(0)))
+    }
 
-        return Ok(())
+    async fn fetch_down_checksum(&mut self, version_id: i64) -> anyhow::Result<Option<String>> {
+        let conn = self.connection().await?;
+
+        Ok(conn
+            .query_opt(
+                "SELECT checksum FROM swellow_down_checksums WHERE version_id = $1",
+                &[&version_id],
+            )
+            .await?
+            .map(|row| row.get::<_, String>(0)))
+    }
+
+    async fn upsert_down_checksum(&mut self, version_id: i64, checksum: &str) -> anyhow::Result<()> {
+        let conn = self.connection().await?;
+
+        conn.execute(
+            r#"
+            INSERT INTO swellow_down_checksums(version_id, checksum)
+            VALUES ($1, $2)
+            ON CONFLICT (version_id) DO UPDATE SET
+                checksum = EXCLUDED.checksum,
+                dtm_updated_at = now()
+            "#,
+            &[&version_id, &checksum],
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn fetch_applied_versions(&mut self) -> anyhow::Result<Vec<i64>> {
+        let conn = self.connection().await?;
+
+        Ok(conn
+            .query(
+                "SELECT DISTINCT version_id FROM swellow_records WHERE status IN ('APPLIED', 'TESTED') ORDER BY version_id",
+                &[],
+            )
+            .await?
+            .iter()
+            .map(|row| row.get::<_, i64>(0))
+            .collect())
+    }
+
+    async fn acquire_lock(&mut self, lock_mode: super::LockMode) -> anyhow::Result<()> {
+        let conn = self.connection().await?;
+
+        match lock_mode {
+            // Blocks every reader of swellow_records for the whole migration,
+            // released automatically when the transaction ends.
+            super::LockMode::Table => {
+                conn.execute("LOCK TABLE swellow_records IN ACCESS EXCLUSIVE MODE;", &[]).await?;
+            }
+            // Session-level lock: still serializes concurrent swellow
+            // processes, but ordinary queries can keep reading
+            // swellow_records while the migration is in flight. `_try_` is
+            // non-blocking - a lock already held by another migrator fails
+            // fast with LockConflict instead of queueing behind it (and
+            // potentially behind a connection pooler that never lets the
+            // blocking variant's session through at all).
+            super::LockMode::Advisory => {
+                let acquired: bool = conn
+                    .query_one("SELECT pg_try_advisory_lock(hashtext($1));", &[&self.advisory_lock_key])
+                    .await?
+                    .get(0);
+
+                if !acquired {
+                    return Err(super::EngineError { kind: super::EngineErrorKind::LockConflict }.into());
+                }
+            }
+        }
+
+        // The locks above serialize concurrent `acquire_lock` calls, but are
+        // released the instant a crashed process' connection drops - leaving
+        // no record of who held them or for how long. The lease row below is
+        // the crash-safe complement: it's refused only if another owner's
+        // lease hasn't expired, and is stolen outright once it has.
+        let existing = conn.query_opt(
+            "SELECT owner_id, expires_at > now() AS alive FROM swellow_locks WHERE id = 'swellow_records'",
+            &[],
+        ).await?;
+
+        if let Some(row) = &existing {
+            let owner_id: String = row.get(0);
+            let alive: bool = row.get(1);
+            if alive && owner_id != self.lease_owner {
+                return Err(super::EngineError { kind: super::EngineErrorKind::LockConflict }.into());
+            }
+        }
+
+        conn.execute(
+            r#"
+            INSERT INTO swellow_locks (id, owner_id, acquired_at, expires_at)
+            VALUES ('swellow_records', $1, now(), now() + make_interval(secs => $2))
+            ON CONFLICT (id) DO UPDATE SET
+                owner_id = EXCLUDED.owner_id,
+                acquired_at = EXCLUDED.acquired_at,
+                expires_at = EXCLUDED.expires_at
+            "#,
+            &[&self.lease_owner, &(super::LOCK_LEASE_SECONDS as f64)],
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn acquire_xact_lock(&mut self, no_wait: bool) -> anyhow::Result<()> {
+        let conn = self.connection().await?;
+
+        if no_wait {
+            let acquired: bool = conn
+                .query_one("SELECT pg_try_advisory_xact_lock(hashtext($1));", &[&super::XACT_LOCK_KEY.to_string()])
+                .await?
+                .get(0);
+
+            if !acquired {
+                return Err(super::EngineError { kind: super::EngineErrorKind::LockConflict }.into());
+            }
+        } else {
+            conn.execute("SELECT pg_advisory_xact_lock(hashtext($1));", &[&super::XACT_LOCK_KEY.to_string()]).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn heartbeat(&mut self) -> anyhow::Result<()> {
+        let conn = self.connection().await?;
+
+        let affected = conn.execute(
+            r#"
+            UPDATE swellow_locks
+            SET expires_at = now() + make_interval(secs => $1)
+            WHERE id = 'swellow_records' AND owner_id = $2
+            "#,
+            &[&(super::LOCK_LEASE_SECONDS as f64), &self.lease_owner],
+        ).await?;
+
+        if affected == 0 {
+            anyhow::bail!("Lease for 'swellow_records' was stolen by another owner");
+        }
+
+        Ok(())
+    }
+
+    async fn release_lock(&mut self) -> anyhow::Result<()> {
+        // pg_advisory_unlock must run on the same session that acquired the
+        // lock, so this has to happen before the transaction's connection is
+        // dropped back into the pool - a freshly checked-out connection
+        // isn't guaranteed to be the same one. A no-op if LockMode::Table
+        // (or --ignore-locks) was used instead, since nothing was locked.
+        if let Some(conn) = self.conn.take() {
+            conn.execute("SELECT pg_advisory_unlock(hashtext($1));", &[&self.advisory_lock_key]).await?;
+        }
+
+        let conn = self.pool.get().await.map_err(|e| super::EngineError {
+            kind: super::EngineErrorKind::PostgresPool(e),
+        })?;
+
+        conn.execute(
+            "DELETE FROM swellow_locks WHERE id = 'swellow_records' AND owner_id = $1",
+            &[&self.lease_owner],
+        ).await?;
+
+        Ok(())
     }
 
     async fn disable_records(&mut self, current_version_id: i64) -> anyhow::Result<()> {
-        let tx = self.transaction().await?;
+        let conn = self.connection().await?;
 
-        sqlx::query(
+        conn.execute(
             r#"
             UPDATE swellow_records
             SET status='DISABLED'
             WHERE version_id > $1
             "#,
-        )
-            .bind(current_version_id)
-            .execute(&mut **tx)
-            .await?;
+            &[&current_version_id],
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn disable_records_in(&mut self, version_ids: &[i64]) -> anyhow::Result<()> {
+        let conn = self.connection().await?;
+
+        // tokio_postgres binds a Rust slice straight to a Postgres array
+        // parameter, so `= ANY($1)` matches the whole set in one round
+        // trip instead of building a dynamic `IN (?, ?, ...)` string.
+        conn.execute(
+            r#"
+            UPDATE swellow_records
+            SET status='DISABLED'
+            WHERE version_id = ANY($1)
+            "#,
+            &[&version_ids],
+        ).await?;
+
         Ok(())
     }
 
+    async fn fetch_records_in(&mut self, version_ids: &[i64]) -> anyhow::Result<Vec<i64>> {
+        let conn = self.connection().await?;
+
+        Ok(conn
+            .query(
+                "SELECT DISTINCT version_id FROM swellow_records WHERE version_id = ANY($1)",
+                &[&version_ids],
+            )
+            .await?
+            .iter()
+            .map(|row| row.get::<_, i64>(0))
+            .collect())
+    }
+
     async fn upsert_record(
         &mut self,
         object_type: &sqlparser::ast::ObjectType,
-        object_name_before: &String,
-        object_name_after: &String,
+        object_name_before: &str,
+        object_name_after: &str,
         version_id: i64,
-        file_path: &path::PathBuf
+        checksum: &str
     ) -> anyhow::Result<()> {
-        let tx = self.transaction().await?;
+        let object_type = object_type.to_string();
+        let conn = self.connection().await?;
 
-        sqlx::query(
+        conn.execute(
             r#"
             INSERT INTO swellow_records(
                 object_type,
@@ -133,28 +487,22 @@ impl DbEngine for PostgresEngine {
                 $3,
                 $4,
                 'READY',
-                md5($5)
+                $5
             )
             ON CONFLICT (version_id, object_type, object_name_before, object_name_after)
             DO UPDATE SET
                 status = EXCLUDED.status,
                 checksum = EXCLUDED.checksum
             "#,
-        )
-            .bind(object_type.to_string())
-            .bind(object_name_before)
-            .bind(object_name_after)
-            .bind(version_id)
-            .bind(file_checksum(&file_path)?)
-            .execute(&mut **tx)
-            .await?;
+            &[&object_type, &object_name_before, &object_name_after, &version_id, &checksum],
+        ).await?;
 
         Ok(())
     }
-    async fn update_record(&mut self, status: &str, version_id: i64) -> anyhow::Result<()> {
-        let tx = self.transaction().await?;
+    async fn update_record(&mut self, status: super::RecordStatus, version_id: i64) -> anyhow::Result<()> {
+        let conn = self.connection().await?;
 
-        sqlx::query(
+        conn.execute(
             r#"
             UPDATE swellow_records
             SET
@@ -162,25 +510,25 @@ impl DbEngine for PostgresEngine {
             WHERE
                 version_id=$2
             "#,
-        )
-            .bind(status)
-            .bind(version_id)
-            .execute(&mut **tx)
-            .await?;
-        
+            &[&status.as_str(), &version_id],
+        ).await?;
+
         Ok(())
     }
 
     async fn rollback(&mut self) -> anyhow::Result<()> {
-        if let Some(tx) = self.tx.take() {
-            tx.rollback().await?;
+        // The connection is kept checked out (not `.take()`n) past the end
+        // of the transaction so `release_lock` can unlock the advisory lock
+        // on this same session before finally returning it to the pool.
+        if let Some(conn) = &self.conn {
+            conn.batch_execute("ROLLBACK").await?;
         }
         Ok(())
     }
-    
+
     async fn commit(&mut self) -> anyhow::Result<()> {
-        if let Some(tx) = self.tx.take() {
-            tx.commit().await?;
+        if let Some(conn) = &self.conn {
+            conn.batch_execute("COMMIT").await?;
         }
         Ok(())
     }
@@ -192,13 +540,46 @@ impl DbEngine for PostgresEngine {
             tracing::error!("pg_dump not installed or not in PATH.");
             std::process::exit(1);
         }
-        let output = process::Command::new("pg_dump")
-            .arg("--schema-only") // only schema, no data
+
+        // pg_dump takes discrete -h/-p/-U/-d flags, not a connection URI, so
+        // the raw conn_str can't just be forwarded verbatim - and doing so
+        // broke outright on a unix-socket DSN, since pg_dump would try (and
+        // fail) to resolve the whole string as a TCP hostname.
+        let dsn = Dsn::parse(&self.conn_str)?;
+
+        let mut cmd = process::Command::new("pg_dump");
+        cmd.arg("--schema-only") // only schema, no data
             .arg("--no-owner")    // drop ownership info
-            .arg("--no-privileges")
-            .arg(&self.conn_str)
-            .output()?;
-        
+            .arg("--no-privileges");
+
+        // libpq (and therefore pg_dump) expects a unix-socket "host" to be
+        // the socket's containing directory, not the socket file itself.
+        if let Some(socket) = &dsn.unix_socket {
+            let socket_dir = path::Path::new(socket)
+                .parent()
+                .map(|p| p.to_string_lossy().to_string())
+                .unwrap_or_else(|| socket.clone());
+            cmd.arg("-h").arg(socket_dir);
+        } else if let Some(host) = &dsn.host {
+            cmd.arg("-h").arg(host);
+        }
+
+        if let Some(port) = dsn.port {
+            cmd.arg("-p").arg(port.to_string());
+        }
+        if let Some(user) = &dsn.user {
+            cmd.arg("-U").arg(user);
+        }
+        if let Some(database) = &dsn.database {
+            cmd.arg("-d").arg(database);
+        }
+        // pg_dump has no password flag - it reads PGPASSWORD instead.
+        if let Some(password) = &dsn.password {
+            cmd.env("PGPASSWORD", password);
+        }
+
+        let output = cmd.output()?;
+
         if output.status.success() {
             return Ok(output.stdout)
         } else {
@@ -206,3 +587,52 @@ impl DbEngine for PostgresEngine {
         }
     }
 }
+
+/// Provisions `conn_str`'s target database, connecting instead to a
+/// maintenance database - Postgres refuses to `CREATE DATABASE` against the
+/// database a connection is already using. Follows `createdb`'s own
+/// fallback: maintenance connects to `postgres`, or `template1` when the
+/// target database is itself named `postgres`.
+pub async fn create_database(conn_str: &str, tls: TlsConfig, if_not_exists: bool) -> anyhow::Result<()> {
+    let dsn = Dsn::parse(conn_str)?;
+    dsn.validate_scheme(&["postgresql", "postgres"])?;
+
+    let target = dsn.database.clone().ok_or_else(|| super::EngineError {
+        kind: super::EngineErrorKind::InvalidDsn { reason: "Missing target database name".to_string() },
+    })?;
+
+    let maintenance_db = if target == "postgres" { "template1" } else { "postgres" };
+    let maintenance_conn_str = conn_str.replacen(&format!("/{target}"), &format!("/{maintenance_db}"), 1);
+
+    let mut pool_config = deadpool_postgres::Config::default();
+    pool_config.url = Some(maintenance_conn_str);
+
+    let pool = if tls.mode == SslMode::Disable {
+        pool_config.create_pool(Some(Runtime::Tokio1), NoTls)?
+    } else {
+        pool_config.create_pool(Some(Runtime::Tokio1), build_tls_connector(&tls)?)?
+    };
+
+    let conn = pool.get().await.map_err(|e| super::EngineError {
+        kind: super::EngineErrorKind::PostgresPool(e),
+    })?;
+
+    let exists = conn
+        .query_opt("SELECT 1 FROM pg_database WHERE datname = $1", &[&target])
+        .await?
+        .is_some();
+
+    if exists {
+        if if_not_exists {
+            return Ok(());
+        }
+        anyhow::bail!("Database '{target}' already exists");
+    }
+
+    // CREATE DATABASE doesn't accept bind parameters for the database name -
+    // quote the identifier instead, doubling any embedded '"'.
+    let quoted = target.replace('"', "\"\"");
+    conn.batch_execute(&format!("CREATE DATABASE \"{quoted}\"")).await?;
+
+    Ok(())
+}
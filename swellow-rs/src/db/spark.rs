@@ -1,5 +1,5 @@
 use super::DbEngine;
-use arrow::{self, array::Array, array::Int64Array, array::RecordBatch};
+use arrow::{self, array::Array, array::Int64Array, array::RecordBatch, array::StringArray};
 use spark_connect as spark;
 
 
@@ -16,14 +16,23 @@ pub enum SparkCatalog {
 pub struct SparkEngine {
     catalog: SparkCatalog,
     session: spark::SparkSession,
-    // snapshot: 
+    /// Identifies this instance's `swellow_locks` lease, so a heartbeat or
+    /// release only ever touches a lease this instance actually holds.
+    lease_owner: String,
+    // snapshot:
 }
 
 impl SparkEngine {
-    pub async fn new(conn_str: &str, catalog: SparkCatalog) -> anyhow::Result<Self, spark::SparkError> {
+    pub async fn new(conn_str: &str, catalog: SparkCatalog, headers_file: Option<&str>) -> anyhow::Result<Self, spark::SparkError> {
+        let mut builder = spark::SparkSessionBuilder::new(conn_str);
+        if let Some(path) = headers_file {
+            builder = builder.headers_from_file(path)?;
+        }
+
         return Ok(SparkEngine {
             catalog: catalog,
-            session: spark::SparkSessionBuilder::new(conn_str).build().await?
+            session: builder.build().await?,
+            lease_owner: uuid::Uuid::new_v4().to_string(),
         })
     }
 
@@ -55,6 +64,27 @@ impl SparkEngine {
 
         Ok(results)
     }
+
+    /// Fetch an optional single string column value
+    async fn fetch_optional_string(&mut self, sql: &str) -> anyhow::Result<Option<String>> {
+        let batches: Vec<RecordBatch> = self.sql(sql).await?;
+
+        let first_batch = match batches.first() {
+            Some(batch) => batch,
+            None => return Ok(None),
+        };
+
+        let first_column = match first_batch.column(0).as_any().downcast_ref::<StringArray>() {
+            Some(col) => col,
+            None => anyhow::bail!("Expected first column to be StringArray"),
+        };
+
+        if first_column.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(first_column.value(0).to_string()))
+    }
 }
 
 
@@ -83,6 +113,32 @@ impl DbEngine for SparkEngine {
 
         self.session.query(&sql).execute().await?;
 
+        let lock_sql = format!(r#"
+            CREATE TABLE IF NOT EXISTS swellow_locks (
+                id STRING,
+                owner_id STRING,
+                acquired_at TIMESTAMP,
+                expires_at TIMESTAMP
+            )
+            USING {using_clause};
+        "#);
+
+        self.session.query(&lock_sql).execute().await?;
+
+        // Tracked separately from swellow_records.checksum (which is over
+        // up.sql) so an edited down.sql is detected even though it's never
+        // parsed into per-resource records.
+        let down_checksum_sql = format!(r#"
+            CREATE TABLE IF NOT EXISTS swellow_down_checksums (
+                version_id BIGINT,
+                checksum STRING,
+                dtm_updated_at TIMESTAMP
+            )
+            USING {using_clause};
+        "#);
+
+        self.session.query(&down_checksum_sql).execute().await?;
+
         Ok(())
     }
 
@@ -95,6 +151,13 @@ impl DbEngine for SparkEngine {
         Ok(())
     }
 
+    /// `begin`/`commit`/`rollback` are already no-ops on this engine, so
+    /// every statement already runs standalone - delegates straight to
+    /// [`Self::execute`].
+    async fn execute_standalone(&mut self, sql: &str) -> anyhow::Result<()> {
+        self.execute(sql).await
+    }
+
     /// Fetch an optional single column value
     async fn fetch_optional_i64(&mut self, sql: &str) -> anyhow::Result<Option<i64>> {
         let batches: Vec<RecordBatch> = self.sql(sql).await?;
@@ -120,50 +183,110 @@ impl DbEngine for SparkEngine {
         Ok(Some(first_column.value(0)))
     }
 
-    async fn acquire_lock(&mut self) -> anyhow::Result<()> {
-        let query = r#"
-            MERGE INTO swellow_records t
+    async fn fetch_checksum(&mut self, version_id: i64) -> anyhow::Result<Option<String>> {
+        self.fetch_optional_string(&format!(
+            "SELECT checksum FROM swellow_records WHERE version_id = {version_id} LIMIT 1"
+        )).await
+    }
+
+    async fn fetch_down_checksum(&mut self, version_id: i64) -> anyhow::Result<Option<String>> {
+        self.fetch_optional_string(&format!(
+            "SELECT checksum FROM swellow_down_checksums WHERE version_id = {version_id} LIMIT 1"
+        )).await
+    }
+
+    async fn upsert_down_checksum(&mut self, version_id: i64, checksum: &str) -> anyhow::Result<()> {
+        self.session.query(r#"
+            MERGE INTO swellow_down_checksums AS target
             USING (
-                SELECT 0 AS version_id,
-                    'LOCK' AS object_type,
-                    'LOCK' AS object_name_before,
-                    'LOCK' AS object_name_after,
-                    'LOCKED' AS status,
-                    'LOCK' AS checksum,
-                    current_timestamp() AS dtm_updated_at
-            ) s
-            ON t.version_id = s.version_id
-            AND t.object_type = s.object_type
-            AND t.object_name_before = s.object_name_before
-            AND t.object_name_after = s.object_name_after
+                SELECT ? AS version_id, ? AS checksum
+            ) AS source
+            ON target.version_id = source.version_id
+            WHEN MATCHED THEN
+                UPDATE SET target.checksum = source.checksum, target.dtm_updated_at = current_timestamp()
             WHEN NOT MATCHED THEN
-            INSERT (
-                version_id,
-                object_type,
-                object_name_before,
-                object_name_after,
-                status,
-                checksum,
-                dtm_created_at,
-                dtm_updated_at
-            )
-            VALUES (
-                s.version_id,
-                s.object_type,
-                s.object_name_before,
-                s.object_name_after,
-                s.status,
-                s.checksum,
-                current_timestamp(),
-                current_timestamp()
-            )
-        "#;
-        
-        if self.fetch_optional_i64(query).await?.is_none() {
-            anyhow::bail!("Lock already exists!")
+                INSERT (version_id, checksum, dtm_updated_at)
+                VALUES (source.version_id, source.checksum, current_timestamp())
+        "#)
+            .bind(version_id)
+            .bind(checksum.to_string())
+            .execute()
+            .await?;
+
+        Ok(())
+    }
+
+    async fn fetch_applied_versions(&mut self) -> anyhow::Result<Vec<i64>> {
+        self.fetch_all_i64(
+            "SELECT DISTINCT version_id FROM swellow_records WHERE status IN ('APPLIED', 'TESTED') ORDER BY version_id",
+            "version_id",
+        ).await
+    }
+
+    async fn acquire_lock(&mut self, _lock_mode: super::LockMode) -> anyhow::Result<()> {
+        // Catalog tables have no session-scoped lock to fall back on like the
+        // SQL engines do, so the lease row in swellow_locks is the only
+        // mutual-exclusion mechanism here: refused only if another owner's
+        // lease hasn't expired, stolen outright once it has.
+        let existing_owner = self.fetch_optional_string(
+            "SELECT owner_id FROM swellow_locks WHERE id = 'swellow_records' AND expires_at > current_timestamp() LIMIT 1"
+        ).await?;
+
+        if let Some(existing_owner) = existing_owner {
+            if existing_owner != self.lease_owner {
+                return Err(super::EngineError { kind: super::EngineErrorKind::LockConflict }.into());
+            }
         }
 
-        return Ok(())
+        let query = format!(r#"
+            MERGE INTO swellow_locks t
+            USING (
+                SELECT
+                    'swellow_records' AS id,
+                    ? AS owner_id,
+                    current_timestamp() AS acquired_at,
+                    current_timestamp() + INTERVAL {} SECONDS AS expires_at
+            ) s
+            ON t.id = s.id
+            WHEN MATCHED THEN UPDATE SET
+                t.owner_id = s.owner_id,
+                t.acquired_at = s.acquired_at,
+                t.expires_at = s.expires_at
+            WHEN NOT MATCHED THEN
+            INSERT (id, owner_id, acquired_at, expires_at)
+            VALUES (s.id, s.owner_id, s.acquired_at, s.expires_at)
+        "#, super::LOCK_LEASE_SECONDS);
+
+        self.session.query(&query).bind(self.lease_owner.clone()).execute().await?;
+
+        Ok(())
+    }
+
+    async fn acquire_xact_lock(&mut self, _no_wait: bool) -> anyhow::Result<()> {
+        // The swellow_locks lease row in acquire_lock above is already this
+        // engine's only cross-process lock; there's no separate
+        // transaction-scoped primitive to take here.
+        Ok(())
+    }
+
+    async fn heartbeat(&mut self) -> anyhow::Result<()> {
+        let query = format!(
+            "UPDATE swellow_locks SET expires_at = current_timestamp() + INTERVAL {} SECONDS WHERE id = 'swellow_records' AND owner_id = ?",
+            super::LOCK_LEASE_SECONDS,
+        );
+
+        self.session.query(&query).bind(self.lease_owner.clone()).execute().await?;
+
+        Ok(())
+    }
+
+    async fn release_lock(&mut self) -> anyhow::Result<()> {
+        self.session.query("DELETE FROM swellow_locks WHERE id = 'swellow_records' AND owner_id = ?")
+            .bind(self.lease_owner.clone())
+            .execute()
+            .await?;
+
+        Ok(())
     }
 
     async fn disable_records(&mut self, current_version_id: i64) -> anyhow::Result<()> {
@@ -179,6 +302,28 @@ impl DbEngine for SparkEngine {
         Ok(())
     }
 
+    async fn disable_records_in(&mut self, version_ids: &[i64]) -> anyhow::Result<()> {
+        // Spark SQL has no array bind parameter - the ids are interpolated
+        // directly, same as fetch_checksum's version_id, since they're
+        // plain i64s rather than user-supplied strings.
+        let list = version_ids.iter().map(i64::to_string).collect::<Vec<_>>().join(", ");
+
+        self.session.query(&format!(
+            "UPDATE swellow_records SET status='DISABLED' WHERE version_id IN ({list})"
+        )).execute().await?;
+
+        Ok(())
+    }
+
+    async fn fetch_records_in(&mut self, version_ids: &[i64]) -> anyhow::Result<Vec<i64>> {
+        let list = version_ids.iter().map(i64::to_string).collect::<Vec<_>>().join(", ");
+
+        self.fetch_all_i64(
+            &format!("SELECT DISTINCT version_id FROM swellow_records WHERE version_id IN ({list})"),
+            "version_id",
+        ).await
+    }
+
     async fn upsert_record(
         &mut self,
         object_type: &sqlparser::ast::ObjectType,
@@ -234,7 +379,7 @@ impl DbEngine for SparkEngine {
         Ok(())
     }
 
-    async fn update_record(&mut self, status: &str, version_id: i64) -> anyhow::Result<()> {
+    async fn update_record(&mut self, status: super::RecordStatus, version_id: i64) -> anyhow::Result<()> {
         self.session.query(r#"
             UPDATE swellow_records
             SET
@@ -242,11 +387,11 @@ impl DbEngine for SparkEngine {
             WHERE
                 version_id=?
         "#)
-            .bind(status)
+            .bind(status.as_str())
             .bind(version_id)
             .execute()
             .await?;
-        
+
         Ok(())
     }
 
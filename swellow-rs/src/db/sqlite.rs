@@ -0,0 +1,399 @@
+use super::{DbEngine, Dsn};
+use sqlx::{Sqlite, SqlitePool, Transaction};
+use std::process;
+
+pub struct SqliteEngine {
+    pool: SqlitePool,
+    conn_str: String,
+    tx: Option<Transaction<'static, Sqlite>>,
+    /// Identifies this instance's `swellow_locks` lease, so a heartbeat or
+    /// release only ever touches a lease this instance actually holds.
+    lease_owner: String,
+}
+
+
+impl SqliteEngine {
+    /// Builds a long-lived connection pool once, reused by every call
+    /// instead of reconnecting on each operation.
+    pub async fn new(conn_str: &str) -> anyhow::Result<Self, super::EngineError> {
+        Self::with_pool_options(conn_str, sqlx::sqlite::SqlitePoolOptions::new()).await
+    }
+
+    /// As [`SqliteEngine::new`], but lets the caller size the pool.
+    pub async fn with_pool_options(
+        conn_str: &str,
+        options: sqlx::sqlite::SqlitePoolOptions,
+    ) -> anyhow::Result<Self, super::EngineError> {
+        Dsn::parse(conn_str)?.validate_scheme(&["sqlite"])?;
+
+        let pool = options.connect(conn_str).await?;
+
+        Ok(SqliteEngine {
+            pool,
+            conn_str: conn_str.to_string(),
+            tx: None,
+            lease_owner: uuid::Uuid::new_v4().to_string(),
+        })
+    }
+
+    async fn transaction(&mut self) -> anyhow::Result<&mut Transaction<'static, Sqlite>> {
+        if self.tx.is_none() {
+            let txn = self.pool.begin().await?;
+            self.tx = Some(txn);
+        }
+
+        Ok(self.tx.as_mut().unwrap())
+    }
+}
+
+
+impl DbEngine for SqliteEngine {
+    async fn ensure_table(&self) -> anyhow::Result<()> {
+        // SQLite has no OID type, pgcrypto extension, or now() function -
+        // substitute an INTEGER primary key and CURRENT_TIMESTAMP.
+        sqlx::query(r#"
+            CREATE TABLE IF NOT EXISTS swellow_records (
+                oid INTEGER,
+                version_id BIGINT NOT NULL,
+                object_type TEXT NOT NULL,
+                object_name_before TEXT NOT NULL,
+                object_name_after TEXT NOT NULL,
+                status TEXT NOT NULL,
+                checksum TEXT NOT NULL,
+                dtm_created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                dtm_updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                PRIMARY KEY (version_id, object_type, object_name_before, object_name_after)
+            );
+        "#)
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(r#"
+            CREATE TABLE IF NOT EXISTS swellow_locks (
+                id TEXT PRIMARY KEY,
+                owner_id TEXT NOT NULL,
+                acquired_at TIMESTAMP NOT NULL,
+                expires_at TIMESTAMP NOT NULL
+            );
+        "#)
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS swellow_locks_expires_at_idx ON swellow_locks (expires_at);")
+            .execute(&self.pool)
+            .await?;
+
+        // Tracked separately from swellow_records.checksum (which is over
+        // up.sql) so an edited down.sql is detected even though it's never
+        // parsed into per-resource records.
+        sqlx::query(r#"
+            CREATE TABLE IF NOT EXISTS swellow_down_checksums (
+                version_id BIGINT PRIMARY KEY,
+                checksum TEXT NOT NULL,
+                dtm_updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            );
+        "#)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn begin(&mut self) -> anyhow::Result<()> {
+        self.transaction().await?;
+
+        Ok(())
+    }
+
+    async fn execute(&mut self, sql: &str) -> anyhow::Result<()> {
+        let tx = self.transaction().await?;
+
+        sqlx::raw_sql(&sql)
+            .execute(&mut **tx)
+            .await?;
+
+        Ok(())
+    }
+
+    /// SQLite has no equivalent of `CREATE INDEX CONCURRENTLY`, so there's
+    /// no transaction to escape - delegates straight to [`Self::execute`].
+    async fn execute_standalone(&mut self, sql: &str) -> anyhow::Result<()> {
+        self.execute(sql).await
+    }
+
+    /// Fetch an optional single column value
+    async fn fetch_optional_i64(&mut self, sql: &str) -> anyhow::Result<Option<i64>> {
+        let tx = self.transaction().await?;
+
+        Ok(sqlx::query_scalar(sql)
+            .fetch_one(&mut **tx)
+            .await?)
+    }
+
+    async fn fetch_checksum(&mut self, version_id: i64) -> anyhow::Result<Option<String>> {
+        let tx = self.transaction().await?;
+
+        Ok(sqlx::query_scalar(
+            "SELECT checksum FROM swellow_records WHERE version_id = ? LIMIT 1",
+        )
+            .bind(version_id)
+            .fetch_optional(&mut **tx)
+            .await?)
+    }
+
+    async fn fetch_down_checksum(&mut self, version_id: i64) -> anyhow::Result<Option<String>> {
+        let tx = self.transaction().await?;
+
+        Ok(sqlx::query_scalar(
+            "SELECT checksum FROM swellow_down_checksums WHERE version_id = ?",
+        )
+            .bind(version_id)
+            .fetch_optional(&mut **tx)
+            .await?)
+    }
+
+    async fn upsert_down_checksum(&mut self, version_id: i64, checksum: &str) -> anyhow::Result<()> {
+        let tx = self.transaction().await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO swellow_down_checksums(version_id, checksum)
+            VALUES (?, ?)
+            ON CONFLICT (version_id) DO UPDATE SET
+                checksum = excluded.checksum,
+                dtm_updated_at = CURRENT_TIMESTAMP
+            "#,
+        )
+            .bind(version_id)
+            .bind(checksum)
+            .execute(&mut **tx)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn fetch_applied_versions(&mut self) -> anyhow::Result<Vec<i64>> {
+        let tx = self.transaction().await?;
+
+        Ok(sqlx::query_scalar(
+            "SELECT DISTINCT version_id FROM swellow_records WHERE status IN ('APPLIED', 'TESTED') ORDER BY version_id",
+        )
+            .fetch_all(&mut **tx)
+            .await?)
+    }
+
+    async fn acquire_lock(&mut self, _lock_mode: super::LockMode) -> anyhow::Result<()> {
+        // SQLite serializes writers at the database-file level, so there is
+        // no equivalent of Postgres' table-level ACCESS EXCLUSIVE lock (or
+        // its advisory-lock alternative) - a write transaction already
+        // blocks every other writer. The lease row below is what survives a
+        // crash: it lets a later process tell an abandoned lock (expired)
+        // apart from one still legitimately held.
+        let tx = self.transaction().await?;
+
+        let existing: Option<(String, bool)> = sqlx::query_as(
+            "SELECT owner_id, expires_at > CURRENT_TIMESTAMP AS alive FROM swellow_locks WHERE id = 'swellow_records'",
+        )
+            .fetch_optional(&mut **tx)
+            .await?;
+
+        if let Some((owner_id, alive)) = existing {
+            if alive && owner_id != self.lease_owner {
+                return Err(super::EngineError { kind: super::EngineErrorKind::LockConflict }.into());
+            }
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO swellow_locks (id, owner_id, acquired_at, expires_at)
+            VALUES ('swellow_records', ?, CURRENT_TIMESTAMP, datetime(CURRENT_TIMESTAMP, '+' || ? || ' seconds'))
+            ON CONFLICT (id) DO UPDATE SET
+                owner_id = excluded.owner_id,
+                acquired_at = excluded.acquired_at,
+                expires_at = excluded.expires_at
+            "#,
+        )
+            .bind(&self.lease_owner)
+            .bind(super::LOCK_LEASE_SECONDS)
+            .execute(&mut **tx)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn acquire_xact_lock(&mut self, _no_wait: bool) -> anyhow::Result<()> {
+        // SQLite write transactions already block every other writer (see
+        // acquire_lock above), so there's no separate cross-process lock to
+        // take here.
+        Ok(())
+    }
+
+    async fn heartbeat(&mut self) -> anyhow::Result<()> {
+        let tx = self.transaction().await?;
+
+        let result = sqlx::query(
+            r#"
+            UPDATE swellow_locks
+            SET expires_at = datetime(CURRENT_TIMESTAMP, '+' || ? || ' seconds')
+            WHERE id = 'swellow_records' AND owner_id = ?
+            "#,
+        )
+            .bind(super::LOCK_LEASE_SECONDS)
+            .bind(&self.lease_owner)
+            .execute(&mut **tx)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            anyhow::bail!("Lease for 'swellow_records' was stolen by another owner");
+        }
+
+        Ok(())
+    }
+
+    async fn release_lock(&mut self) -> anyhow::Result<()> {
+        sqlx::query("DELETE FROM swellow_locks WHERE id = 'swellow_records' AND owner_id = ?")
+            .bind(&self.lease_owner)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn disable_records(&mut self, current_version_id: i64) -> anyhow::Result<()> {
+        let tx = self.transaction().await?;
+
+        sqlx::query(
+            r#"
+            UPDATE swellow_records
+            SET status='DISABLED'
+            WHERE version_id > ?
+            "#,
+        )
+            .bind(current_version_id)
+            .execute(&mut **tx)
+            .await?;
+        Ok(())
+    }
+
+    async fn disable_records_in(&mut self, version_ids: &[i64]) -> anyhow::Result<()> {
+        // SQLite has no array parameter type - bind one placeholder per id.
+        let placeholders = vec!["?"; version_ids.len()].join(", ");
+        let sql = format!("UPDATE swellow_records SET status='DISABLED' WHERE version_id IN ({placeholders})");
+
+        let tx = self.transaction().await?;
+        let mut query = sqlx::query(&sql);
+        for version_id in version_ids {
+            query = query.bind(version_id);
+        }
+        query.execute(&mut **tx).await?;
+
+        Ok(())
+    }
+
+    async fn fetch_records_in(&mut self, version_ids: &[i64]) -> anyhow::Result<Vec<i64>> {
+        let placeholders = vec!["?"; version_ids.len()].join(", ");
+        let sql = format!("SELECT DISTINCT version_id FROM swellow_records WHERE version_id IN ({placeholders})");
+
+        let tx = self.transaction().await?;
+        let mut query = sqlx::query_scalar(&sql);
+        for version_id in version_ids {
+            query = query.bind(version_id);
+        }
+
+        Ok(query.fetch_all(&mut **tx).await?)
+    }
+
+    async fn upsert_record(
+        &mut self,
+        object_type: &sqlparser::ast::ObjectType,
+        object_name_before: &str,
+        object_name_after: &str,
+        version_id: i64,
+        checksum: &str
+    ) -> anyhow::Result<()> {
+        let tx = self.transaction().await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO swellow_records(
+                object_type,
+                object_name_before,
+                object_name_after,
+                version_id,
+                status,
+                checksum
+            )
+            VALUES (?, ?, ?, ?, 'READY', ?)
+            ON CONFLICT (version_id, object_type, object_name_before, object_name_after)
+            DO UPDATE SET
+                status = excluded.status,
+                checksum = excluded.checksum
+            "#,
+        )
+            .bind(object_type.to_string())
+            .bind(object_name_before)
+            .bind(object_name_after)
+            .bind(version_id)
+            .bind(checksum)
+            .execute(&mut **tx)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn update_record(&mut self, status: super::RecordStatus, version_id: i64) -> anyhow::Result<()> {
+        let tx = self.transaction().await?;
+
+        sqlx::query(
+            r#"
+            UPDATE swellow_records
+            SET
+                status=?
+            WHERE
+                version_id=?
+            "#,
+        )
+            .bind(status.as_str())
+            .bind(version_id)
+            .execute(&mut **tx)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn rollback(&mut self) -> anyhow::Result<()> {
+        if let Some(tx) = self.tx.take() {
+            tx.rollback().await?;
+        }
+        Ok(())
+    }
+
+    async fn commit(&mut self) -> anyhow::Result<()> {
+        if let Some(tx) = self.tx.take() {
+            tx.commit().await?;
+        }
+        Ok(())
+    }
+
+    fn snapshot(&mut self) -> anyhow::Result<Vec<u8>> {
+        // SQLite has no pg_dump equivalent server-side, so shell out to the
+        // sqlite3 CLI's .schema meta-command against the database file.
+        if process::Command::new("sqlite3").arg("--version").output().is_err() {
+            tracing::error!("sqlite3 not installed or not in PATH.");
+            std::process::exit(1);
+        }
+
+        let db_path = self.conn_str.trim_start_matches("sqlite://");
+
+        let output = process::Command::new("sqlite3")
+            .arg(db_path)
+            .arg(".schema")
+            .output()?;
+
+        if output.status.success() {
+            Ok(output.stdout)
+        } else {
+            anyhow::bail!("sqlite3 .schema error: {}", String::from_utf8_lossy(&output.stderr))
+        }
+    }
+}
@@ -11,13 +11,87 @@ use output::{SwellowOutput, SwellowStatus};
 use serde_json::Value;
 
 
+/// Wraps a scaffold command's created path into a [`SwellowOutput`], both
+/// logging it (see `migration::scaffold`) and surfacing it as `data` so
+/// `--json` callers can script against the new version's directory.
+fn scaffolded_output(
+    command: impl Into<String>,
+    result: anyhow::Result<std::path::PathBuf>,
+) -> output::SwellowOutput<Value> {
+    let command = command.into();
+    match result {
+        Ok(path) => SwellowOutput {
+            command,
+            status: SwellowStatus::Success,
+            data: serde_json::to_value(path.to_string_lossy()).ok(),
+            error: None,
+        },
+        Err(e) => SwellowOutput {
+            command,
+            status: SwellowStatus::Error,
+            data: None,
+            error: Some(output::SwellowErrorJson::Engine { message: e.to_string() }),
+        },
+    }
+}
+
 async fn run_command(args: &cli::Cli) -> output::SwellowOutput<serde_json::Value> {
-    let db_connection_string: String = args.db_connection_string.clone();
     let migration_directory: String = args.migration_directory.clone();
 
     let command_name = args.command.to_string();
 
-    let mut backend = match args.engine.into_backend(db_connection_string).await {
+    let db_connection_string = match args.resolve_db_connection_string() {
+        Ok(conn_str) => conn_str,
+        Err(e) => return SwellowOutput {
+            command: command_name,
+            status: SwellowStatus::Error,
+            data: None,
+            error: Some((&e).into()),
+        },
+    };
+
+    let tls_config = match args.resolve_tls_config() {
+        Ok(tls) => tls,
+        Err(e) => return SwellowOutput {
+            command: command_name,
+            status: SwellowStatus::Error,
+            data: None,
+            error: Some((&e).into()),
+        },
+    };
+
+    // `create` provisions the database itself, so it must run before - not
+    // through - the normal connection below, which assumes the target
+    // database already exists.
+    if let cli::Commands::Create { if_not_exists } = &args.command {
+        let result = commands::create(args.engine, &db_connection_string, tls_config, *if_not_exists).await;
+        return SwellowOutput {
+            command: command_name,
+            status: if result.is_ok() { SwellowStatus::Success } else { SwellowStatus::Error },
+            data: None,
+            error: result.err().map(|e| output::SwellowErrorJson::Engine { message: e.to_string() }),
+        };
+    }
+
+    // Mirrors `swellow create --if-not-exists`, run ahead of the normal
+    // connection below (which otherwise fails hard against a database that
+    // doesn't exist yet) instead of requiring a separate bootstrap step.
+    if args.auto_create_database {
+        if args.engine == cli::Engine::Postgres {
+            if let Err(e) = db::create_database(&db_connection_string, tls_config.clone(), true).await {
+                return SwellowOutput {
+                    command: command_name,
+                    status: SwellowStatus::Error,
+                    data: None,
+                    error: Some(output::SwellowErrorJson::Engine { message: e.to_string() }),
+                };
+            }
+        } else {
+            tracing::warn!("--auto-create-database is ignored on non-Postgres engines.");
+        }
+    }
+
+    let mut backend = match args.connect_with_retry(db_connection_string, tls_config).await {
         Ok(b) => b,
         Err(e) => return SwellowOutput {
             command: command_name,
@@ -39,10 +113,17 @@ async fn run_command(args: &cli::Cli) -> output::SwellowOutput<serde_json::Value
                 &migration_directory,
                 args.current_version_id,
                 args.target_version_id,
+                args.versions.clone(),
                 migration::MigrationDirection::Up,
                 args.plan,
                 args.dry_run,
                 args.ignore_locks,
+                args.lock_mode.into(),
+                args.lock_no_wait,
+                args.json,
+                args.on_destructive.into(),
+                args.ignore_missing,
+                args.allow_dirty,
             ).await
         ),
         cli::Commands::Down { args } => SwellowOutput::from_result(
@@ -52,12 +133,52 @@ async fn run_command(args: &cli::Cli) -> output::SwellowOutput<serde_json::Value
                 &migration_directory,
                 args.current_version_id,
                 args.target_version_id,
+                args.versions.clone(),
                 migration::MigrationDirection::Down,
                 args.plan,
                 args.dry_run,
                 args.ignore_locks,
+                args.lock_mode.into(),
+                args.lock_no_wait,
+                args.json,
+                args.on_destructive.into(),
+                args.ignore_missing,
+                args.allow_dirty,
             ).await
         ),
+        cli::Commands::Add { description, no_reversible } => scaffolded_output(
+            "add",
+            commands::add(&backend, &migration_directory, description, !no_reversible)
+        ),
+        cli::Commands::New { name, no_reversible } => scaffolded_output(
+            "new",
+            commands::new(&backend, &migration_directory, name, !no_reversible)
+        ),
+        cli::Commands::Verify {} => match commands::verify(&mut backend, &migration_directory).await {
+            Ok(report) => {
+                let has_drift = report.has_drift();
+                let data = serde_json::to_value(&report).ok();
+
+                SwellowOutput {
+                    command: "verify".to_string(),
+                    status: if has_drift { SwellowStatus::Error } else { SwellowStatus::Success },
+                    data,
+                    error: has_drift.then(|| output::SwellowErrorJson::Engine {
+                        message: format!(
+                            "Drift detected: {} modified, {} missing",
+                            report.modified.len(),
+                            report.missing.len(),
+                        ),
+                    }),
+                }
+            }
+            Err(e) => SwellowOutput {
+                command: "verify".to_string(),
+                status: SwellowStatus::Error,
+                data: None,
+                error: Some(output::SwellowErrorJson::Engine { message: e.to_string() }),
+            },
+        },
         cli::Commands::Snapshot { } => SwellowOutput::from_result(
             "snapshot",
             commands::snapshot(
@@ -65,6 +186,8 @@ async fn run_command(args: &cli::Cli) -> output::SwellowOutput<serde_json::Value
                 &migration_directory
             )
         ),
+        // Handled above, before a backend connection is even attempted.
+        cli::Commands::Create { .. } => unreachable!("Commands::Create returns early in run_command"),
     };
 
     match backend.release_lock().await {
@@ -83,9 +206,13 @@ async fn run_command(args: &cli::Cli) -> output::SwellowOutput<serde_json::Value
 ///
 /// This program manages database migrations by delegating to subcommands:
 /// - `peck`: Verify connectivity to the database.
+/// - `add`: Scaffold a new migration directory.
+/// - `new`: Scaffold a new, empty migration directory from a human-readable name.
+/// - `verify`: Detect drift between applied migrations and their on-disk files.
 /// - `up`: Apply migrations forward from the current to target version.
 /// - `down`: Revert migrations backward from the current to target version.
 /// - `snapshot`: Create a snapshot of the current migration state.
+/// - `create`: Provision the target database (Postgres only) before connecting to it.
 ///
 /// Arguments such as `--db` and `--dir` are parsed from the command line
 /// and passed through to the relevant command handlers.
@@ -12,7 +12,26 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 
-/// Extract migration ID from version name: "001_create_users" -> 1
+/// Normalizes a human-entered migration name into a filesystem-safe slug:
+/// lowercased, with runs of characters other than ASCII letters and digits
+/// (non-ASCII letters included) collapsed to a
+/// single underscore and no leading/trailing underscore.
+pub fn slugify(name: &str) -> String {
+    let mut slug = String::with_capacity(name.len());
+    let mut last_was_sep = true;
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+            last_was_sep = false;
+        } else if !last_was_sep {
+            slug.push('_');
+            last_was_sep = true;
+        }
+    }
+    slug.trim_end_matches('_').to_string()
+}
+
+/// Extract migration ID from a version name: "001_create_users" -> 1
 pub fn parse_id_from_version_name(version_name: &str) -> anyhow::Result<i64> {
     version_name
         .split('_')
@@ -28,7 +47,7 @@ pub fn parse_id_from_version_name(version_name: &str) -> anyhow::Result<i64> {
 pub fn collect_versions_from_directory(
     directory: &str,
     from_version_id: i64,
-    to_version_id: i64
+    to_version_id: i64,
 ) -> anyhow::Result<BTreeMap<i64, PathBuf>> {
     let path = Path::new(directory);
     if !path.is_dir() {
@@ -94,11 +113,67 @@ pub fn collect_versions_from_directory(
 }
 
 
-pub struct Migration {
-    pub path: PathBuf,
-    #[allow(dead_code)]
-    sql: String,
-    pub statements: StatementCollection,
+/// Scaffolds a new migration directory under `directory`, named
+/// `<version_id>_<description>`, with an `up.sql` stub (and, unless
+/// `reversible` is false, a `down.sql` stub too) seeded with a dialect-aware
+/// starting comment for `backend` (mirrors `sqlx migrate add` / `diesel
+/// migration generate`). The version ID is one past the highest existing
+/// version, formatted so [`parse_id_from_version_name`] reads it straight
+/// back.
+pub fn scaffold(
+    directory: &str,
+    description: &str,
+    backend: &crate::db::EngineBackend,
+    reversible: bool,
+) -> anyhow::Result<PathBuf> {
+    let next_version_id = collect_versions_from_directory(directory, i64::MIN, i64::MAX)
+        .map(|versions| versions.keys().next_back().copied().unwrap_or(0) + 1)
+        .unwrap_or(1);
+    let dir_name = format!("{:03}_{}", next_version_id, description);
+
+    let version_path = Path::new(directory).join(&dir_name);
+    fs::create_dir_all(&version_path)
+        .map_err(|e| anyhow::format_err!("Failed to create '{:?}': {}", version_path, e))?;
+
+    let up_comment = scaffold_template(backend, &MigrationDirection::Up);
+    fs::write(version_path.join(MigrationDirection::Up.filename()), up_comment)?;
+
+    if reversible {
+        let down_comment = scaffold_template(backend, &MigrationDirection::Down);
+        fs::write(version_path.join(MigrationDirection::Down.filename()), down_comment)?;
+    } else {
+        tracing::info!("--no-reversible set: skipping down.sql for '{}'", dir_name);
+    }
+
+    tracing::info!("Scaffolded migration '{}' at {:?}", dir_name, version_path);
+    Ok(version_path)
+}
+
+/// Starting comment seeded into a freshly scaffolded `up.sql`/`down.sql`, so
+/// the file opens with valid syntax for the target engine instead of being
+/// completely empty. Engines other than Postgres and SQLite (Databricks/Hive
+/// via Spark Connect, MySQL) don't support transactional DDL, so the comment
+/// is a hint rather than a functional template.
+fn scaffold_template(backend: &crate::db::EngineBackend, direction: &MigrationDirection) -> String {
+    let dialect_name = match backend {
+        crate::db::EngineBackend::Postgres(_) => "Postgres",
+        crate::db::EngineBackend::SparkDelta(_) => "Databricks (Delta)",
+        crate::db::EngineBackend::SparkIceberg(_) => "Hive (Iceberg)",
+        crate::db::EngineBackend::Sqlite(_) => "SQLite",
+        crate::db::EngineBackend::MySql(_) => "MySQL",
+    };
+    format!("-- {} {}\n", dialect_name, direction.noun().to_lowercase())
+}
+
+/// A single version's worth of migration logic, backed by an `up.sql`/
+/// `down.sql` pair parsed into a [`StatementCollection`].
+pub enum Migration {
+    FileBased {
+        path: PathBuf,
+        #[allow(dead_code)]
+        sql: String,
+        statements: StatementCollection,
+    },
 }
 
 impl Migration {
@@ -106,7 +181,7 @@ impl Migration {
         let sql = sql.to_string();
         let statements = StatementCollection::new(dialect).parse_sql(&sql);
 
-        Migration { path, sql, statements }
+        Migration::FileBased { path, sql, statements }
     }
 
     pub fn from_file(dialect: ReferenceToStaticDialect, path: PathBuf) -> anyhow::Result<Self> {
@@ -121,6 +196,16 @@ impl Migration {
     }
 
     pub fn resources(&self) -> ResourceCollection {
-        ResourceCollection::from_statement_collection(&self.statements)
+        match self {
+            Migration::FileBased { statements, .. } => ResourceCollection::from_statement_collection(statements),
+        }
+    }
+
+    /// Token-level content checksum of this migration's statements, see
+    /// [`StatementCollection::checksum`].
+    pub fn checksum(&self) -> String {
+        match self {
+            Migration::FileBased { statements, .. } => statements.checksum(),
+        }
     }
 }
\ No newline at end of file
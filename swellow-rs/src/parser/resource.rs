@@ -2,6 +2,7 @@ use crate::parser::error::ParseErrorKind;
 use crate::parser::ParseError;
 use crate::parser::statement::StatementCollection;
 
+use serde::Serialize;
 use sqlparser::ast::{
     ObjectType, Statement, AlterTableOperation, AlterIndexOperation, AlterRoleOperation,
 };
@@ -10,6 +11,37 @@ use std::ops::{Deref, DerefMut};
 use std::vec;
 
 
+/// How risky a resource's accumulated statements are to run unattended,
+/// ordered so `max` over a resource's statements gives its overall
+/// severity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum Severity {
+    Safe,
+    Warning,
+    Destructive,
+}
+
+impl Severity {
+    /// Classifies a single accumulated statement tag (e.g. `"DROP"`,
+    /// `"RENAME"`) by how risky it is to run unattended.
+    pub fn classify(stmt: &str) -> Self {
+        match stmt {
+            "DROP" | "TRUNCATE" => Severity::Destructive,
+            "RENAME" | "ALTER" => Severity::Warning,
+            _ => Severity::Safe,
+        }
+    }
+}
+
+/// Whether a migration plan crossing [`Severity::Destructive`] should just
+/// warn, or should stop the run before anything executes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SeverityPolicy {
+    #[default]
+    Warn,
+    Block,
+}
+
 #[derive(Debug, Clone)]
 pub struct Resource {
     pub object_type: ObjectType,
@@ -32,6 +64,14 @@ impl Resource {
             statements
         }
     }
+
+    /// The highest [`Severity`] among this resource's accumulated statements.
+    pub fn severity(&self) -> Severity {
+        self.statements.iter()
+            .map(|stmt| Severity::classify(stmt))
+            .max()
+            .unwrap_or(Severity::Safe)
+    }
 }
 
 
@@ -181,20 +221,36 @@ impl ResourceCollection {
     }
 
     pub fn from_statement_collection(collection: &StatementCollection) -> Self {
+        Self::try_from_statement_collection(collection).0
+    }
+
+    /// As [`Self::from_statement_collection`], but also returns every
+    /// [`ParseError`] encountered along the way instead of silently treating
+    /// an unsupported statement as contributing no resources.
+    pub fn try_from_statement_collection(collection: &StatementCollection) -> (Self, Vec<ParseError>) {
         let mut resources = ResourceCollection::new();
+        let mut errors = Vec::new();
 
-        for stmt in collection {
-            let resources_in_statement = match ResourceCollection::from_statement(stmt.statement.clone()) {
-                Ok(res) => res,
-                Err(_) => ResourceCollection::new()
+        for tokens in collection {
+            let stmt = match crate::parser::greedy_parse(collection.dialect(), tokens.clone()) {
+                Ok(stmt) => stmt,
+                Err(_) => {
+                    errors.push(ParseError { kind: ParseErrorKind::Tokens(tokens.clone()) });
+                    continue;
+                }
             };
-            
-            for resource in resources_in_statement.iter() {
-                resources.upsert(resource.clone());
+
+            match ResourceCollection::from_statement(stmt) {
+                Ok(resources_in_statement) => {
+                    for resource in resources_in_statement.iter() {
+                        resources.upsert(resource.clone());
+                    }
+                }
+                Err(error) => errors.push(error),
             }
         }
 
-        resources
+        (resources, errors)
     }
 
     pub fn pop_first_match(
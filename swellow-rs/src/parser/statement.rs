@@ -2,11 +2,11 @@ use crate::db::EngineBackend;
 use crate::parser::greedy_parse;
 use crate::parser::dialect::*;
 
+use sha2::{Digest, Sha256};
 use sqlparser::ast::Statement;
 use sqlparser::dialect::Dialect;
 use sqlparser::tokenizer::{Token, Tokenizer};
 use std::fmt;
-use std::hash::{DefaultHasher, Hash, Hasher};
 use std::ops::{Deref, DerefMut};
 
 
@@ -24,6 +24,12 @@ impl StatementCollection {
         }
     }
 
+    /// The SQL dialect this collection parses with, e.g. to reuse it across
+    /// a batch of collections without re-deriving it from the backend each time.
+    pub fn dialect(&self) -> &'static dyn Dialect {
+        self.dialect
+    }
+
     pub fn from_backend(backend: &EngineBackend) -> Self {
         StatementCollection {
             inner: vec![],
@@ -31,6 +37,8 @@ impl StatementCollection {
                 EngineBackend::Postgres(_) => &DIALECT_POSTGRES,
                 EngineBackend::SparkDelta(_) => &DIALECT_DATABRICKS,
                 EngineBackend::SparkIceberg(_) => &DIALECT_HIVE,
+                EngineBackend::Sqlite(_) => &DIALECT_SQLITE,
+                EngineBackend::MySql(_) => &DIALECT_MYSQL,
             },
         }
     }
@@ -69,14 +77,20 @@ impl StatementCollection {
             .collect()
     }
 
-    pub fn checksum(&self) -> u64 {
-        let mut hasher = DefaultHasher::new();
+    /// Deterministic content hash of the collection, stable across Rust
+    /// versions and platforms (unlike `std::hash::DefaultHasher`/SipHash).
+    /// Persisted as a version's `down.sql` checksum (see
+    /// `DbEngine::upsert_down_checksum`) and compared on later runs to detect
+    /// drift between an applied migration and its on-disk `down.sql`.
+    pub fn checksum(&self) -> String {
+        let mut hasher = Sha256::new();
         for tokens in self.iter() {
             for token in tokens {
-                token.hash(&mut hasher);
+                hasher.update(token.to_string().as_bytes());
             }
+            hasher.update(b"\0");
         }
-        hasher.finish()
+        format!("{:x}", hasher.finalize())
     }
 }
 